@@ -0,0 +1,96 @@
+// Renders a tessellated sphere loaded from OBJ text with and without a BVH
+// over its triangles. Run with `cargo bench --bench bvh`.
+
+use std::{
+    f32::consts::{FRAC_PI_3, PI},
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use ray_tracer::{
+    camera::Camera,
+    color::Color,
+    light::PointLight,
+    matrix::Matrix4,
+    obj::ObjFile,
+    shape::{Group, Shape},
+    tuple::{Point, Vector},
+    world::World,
+};
+
+const RINGS: usize = 100;
+const SEGMENTS: usize = 100;
+
+// A unit sphere made of `2 * RINGS * SEGMENTS` triangles, as OBJ text.
+fn sphere_obj() -> String {
+    let mut obj = String::new();
+    for ring in 0..=RINGS {
+        let theta = PI * ring as f32 / RINGS as f32;
+        for segment in 0..SEGMENTS {
+            let phi = 2. * PI * segment as f32 / SEGMENTS as f32;
+            let (x, y, z) = (
+                theta.sin() * phi.cos(),
+                theta.cos(),
+                theta.sin() * phi.sin(),
+            );
+            writeln!(obj, "v {x} {y} {z}").unwrap();
+            writeln!(obj, "vn {x} {y} {z}").unwrap();
+        }
+    }
+
+    for ring in 0..RINGS {
+        for segment in 0..SEGMENTS {
+            let next = (segment + 1) % SEGMENTS;
+            let a = ring * SEGMENTS + segment + 1;
+            let b = ring * SEGMENTS + next + 1;
+            let c = (ring + 1) * SEGMENTS + next + 1;
+            let d = (ring + 1) * SEGMENTS + segment + 1;
+            writeln!(obj, "f {a}//{a} {b}//{b} {c}//{c}").unwrap();
+            writeln!(obj, "f {a}//{a} {c}//{c} {d}//{d}").unwrap();
+        }
+    }
+    obj
+}
+
+fn render(mesh: Group) -> Duration {
+    let world = World::new()
+        .with_light(PointLight::new(Point::new(-10., 10., -10.), Color::WHITE))
+        .with_object(mesh);
+    let camera = Camera::new(80, 60, FRAC_PI_3).with_transform(Matrix4::view_transform(
+        Point::new(0., 1.5, -4.),
+        Point::new(0., 0., 0.),
+        Vector::new(0., 1., 0.),
+    ));
+
+    let start = Instant::now();
+    black_box(camera.render(&world));
+    start.elapsed()
+}
+
+fn main() {
+    let text = sphere_obj();
+
+    let start = Instant::now();
+    let mesh = ObjFile::parse(&text).unwrap().into_group();
+    println!(
+        "parsed {} triangles in {:?}",
+        mesh.children().len(),
+        start.elapsed()
+    );
+
+    let linear = render(mesh);
+    println!("without BVH: {linear:?}");
+
+    let mut mesh = ObjFile::parse(&text).unwrap().into_group();
+    let start = Instant::now();
+    mesh.build_bvh();
+    println!("built BVH in {:?}", start.elapsed());
+
+    let bvh = render(mesh);
+    println!("with BVH:    {bvh:?}");
+    println!(
+        "speedup:     {:.1}x",
+        linear.as_secs_f64() / bvh.as_secs_f64()
+    );
+}
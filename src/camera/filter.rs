@@ -0,0 +1,101 @@
+// How much each sample contributes to its pixel, by its offset from the
+// pixel's center in pixels. Wider filters blur more but alias less; all of
+// them are separable, so the weight is the product of one along each axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Filter {
+    // Every sample within the pixel counts equally.
+    #[default]
+    Box,
+    // Falls off linearly to zero at `radius`.
+    Tent {
+        radius: f32,
+    },
+    // A Gaussian with falloff `alpha`, shifted down so it reaches zero at
+    // `radius` rather than being cut off there.
+    Gaussian {
+        radius: f32,
+        alpha: f32,
+    },
+    // The Mitchell-Netravali cubic over two pixels each way. Its negative
+    // lobes sharpen edges; B = C = 1/3 is the authors' recommendation.
+    Mitchell {
+        b: f32,
+        c: f32,
+    },
+}
+
+impl Filter {
+    pub const fn tent() -> Self {
+        Self::Tent {
+            radius: 1.,
+        }
+    }
+
+    pub const fn gaussian() -> Self {
+        Self::Gaussian {
+            radius: 1.5,
+            alpha: 2.,
+        }
+    }
+
+    pub const fn mitchell() -> Self {
+        Self::Mitchell {
+            b: 1. / 3.,
+            c: 1. / 3.,
+        }
+    }
+
+    // How far from the pixel's center, in pixels, samples still count.
+    pub const fn radius(&self) -> f32 {
+        match *self {
+            Self::Box => 0.5,
+            Self::Tent {
+                radius,
+            }
+            | Self::Gaussian {
+                radius,
+                ..
+            } => radius,
+            Self::Mitchell {
+                ..
+            } => 2.,
+        }
+    }
+
+    pub fn weight(&self, x: f32, y: f32) -> f32 {
+        self.weight_1d(x) * self.weight_1d(y)
+    }
+
+    fn weight_1d(&self, x: f32) -> f32 {
+        let x = x.abs();
+        if x > self.radius() {
+            return 0.;
+        }
+
+        match *self {
+            Self::Box => 1.,
+            Self::Tent {
+                radius,
+            } => radius - x,
+            Self::Gaussian {
+                radius,
+                alpha,
+            } => (-alpha * x * x).exp() - (-alpha * radius * radius).exp(),
+            Self::Mitchell {
+                b,
+                c,
+            } => {
+                let (x2, x3) = (x * x, x * x * x);
+                let weight = if x < 1. {
+                    (12. - 9. * b - 6. * c) * x3 + (-18. + 12. * b + 6. * c) * x2 + (6. - 2. * b)
+                } else {
+                    (-b - 6. * c) * x3
+                        + (6. * b + 30. * c) * x2
+                        + (-12. * b - 48. * c) * x
+                        + (8. * b + 24. * c)
+                };
+                weight / 6.
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+mod filter;
+mod sampler;
+
+pub use filter::Filter;
+pub use sampler::{Sampler, radical_inverse};
+
+use crate::{
+    EPSILON, canvas::Canvas, color::Color, intersection::Intersections, matrix::Matrix4, ray::Ray,
+    rng::Rng, tuple::Point, world::World,
+};
+
+#[derive(Clone, Debug)]
+pub struct Camera {
+    // The view is private because `new` derives the pixel geometry below
+    // from it, and the transform because its inverse is cached.
+    hsize: u32,
+    vsize: u32,
+    field_of_view: f32,
+    transform: Matrix4,
+    inverse: Matrix4,
+    half_width: f32,
+    half_height: f32,
+    pixel_size: f32,
+    pub sampler: Sampler,
+    pub filter: Filter,
+    // Seeds the random numbers used by jittered sampling, so that renders
+    // are reproducible.
+    pub seed: u64,
+}
+
+impl Camera {
+    pub fn new(hsize: u32, vsize: u32, field_of_view: f32) -> Self {
+        // The canvas sits one unit in front of the eye, so half its width is
+        // the tangent of half the field of view along the longer side.
+        let half_view = (field_of_view / 2.).tan();
+        let aspect = hsize as f32 / vsize as f32;
+        let (half_width, half_height) = if aspect >= 1. {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+
+        Self {
+            hsize,
+            vsize,
+            field_of_view,
+            transform: Matrix4::IDENTITY,
+            inverse: Matrix4::IDENTITY,
+            half_width,
+            half_height,
+            pixel_size: half_width * 2. / hsize as f32,
+            sampler: Sampler::default(),
+            filter: Filter::default(),
+            seed: 0,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Matrix4) -> Self {
+        self.set_transform(transform);
+        self
+    }
+
+    pub const fn with_sampler(mut self, sampler: Sampler) -> Self {
+        self.sampler = sampler;
+        self
+    }
+
+    pub const fn with_filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub const fn hsize(&self) -> u32 {
+        self.hsize
+    }
+
+    pub const fn vsize(&self) -> u32 {
+        self.vsize
+    }
+
+    pub const fn field_of_view(&self) -> f32 {
+        self.field_of_view
+    }
+
+    pub const fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.inverse = transform.inverse();
+    }
+
+    pub const fn pixel_size(&self) -> f32 {
+        self.pixel_size
+    }
+
+    pub fn ray_for_pixel(&self, px: u32, py: u32) -> Ray {
+        self.ray_through(px as f32 + 0.5, py as f32 + 0.5)
+    }
+
+    // `x` and `y` are canvas coordinates, with (0, 0) at the top-left corner
+    // of the top-left pixel.
+    fn ray_through(&self, x: f32, y: f32) -> Ray {
+        let world_x = self.half_width - x * self.pixel_size;
+        let world_y = self.half_height - y * self.pixel_size;
+
+        let pixel = self.inverse * Point::new(world_x, world_y, -1.);
+        let origin = self.inverse * Point::new(0., 0., 0.);
+        Ray::new(origin, (pixel - origin).normalize())
+    }
+
+    pub fn render(&self, world: &World) -> Canvas {
+        let mut image = Canvas::new(self.hsize, self.vsize);
+        let mut xs = Intersections::new();
+
+        for y in 0..self.vsize {
+            for x in 0..self.hsize {
+                image.write_pixel(x, y, self.pixel_color(world, x, y, &mut xs));
+            }
+        }
+
+        image
+    }
+
+    // The filtered average of every sample for one pixel. Samples are spread
+    // over the filter's whole support, which may reach into neighbouring
+    // pixels, and each pixel draws its random numbers from its own stream so
+    // the result does not depend on the order pixels are rendered in.
+    pub fn pixel_color<'a>(
+        &self,
+        world: &'a World,
+        px: u32,
+        py: u32,
+        xs: &mut Intersections<'a>,
+    ) -> Color {
+        let count = self.sampler.count();
+        if count == 0 {
+            return Color::BLACK;
+        }
+
+        let mut rng = Rng::with_stream(self.seed, py as u64 * self.hsize as u64 + px as u64);
+        let diameter = 2. * self.filter.radius();
+        let mut weighted = Color::BLACK;
+        let mut total_weight = 0.;
+        let mut unweighted = Color::BLACK;
+
+        for index in 0..count {
+            let (u, v) = self.sampler.sample(index, &mut rng);
+            let (dx, dy) = ((u - 0.5) * diameter, (v - 0.5) * diameter);
+            let ray = self.ray_through(px as f32 + 0.5 + dx, py as f32 + 0.5 + dy);
+            let color = world.color_at(&ray, xs);
+
+            let weight = self.filter.weight(dx, dy);
+            weighted = weighted + color * weight;
+            total_weight += weight;
+            unweighted = unweighted + color;
+        }
+
+        // Negative lobes can cancel out the weights of a few samples; fall
+        // back to a plain average rather than dividing by almost nothing.
+        if total_weight > EPSILON {
+            weighted * (1. / total_weight)
+        } else {
+            unweighted * (1. / count as f32)
+        }
+    }
+}
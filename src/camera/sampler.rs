@@ -0,0 +1,84 @@
+use crate::rng::Rng;
+
+// Where within a pixel to cast its rays. Positions are in [0, 1) on both
+// axes, which the camera stretches over the support of its filter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Sampler {
+    // A single ray through the middle of the pixel.
+    #[default]
+    Center,
+    // An evenly spaced `per_axis` by `per_axis` grid.
+    Regular {
+        per_axis: u32,
+    },
+    // A `per_axis` by `per_axis` grid with each sample moved to a random
+    // spot within its cell, which trades the grid's aliasing for noise.
+    Jittered {
+        per_axis: u32,
+    },
+    // The first `samples` points of the base 2 and 3 Halton sequence, which
+    // cover the pixel evenly for any count, not just perfect squares.
+    Halton {
+        samples: u32,
+    },
+}
+
+impl Sampler {
+    pub const fn count(&self) -> u32 {
+        match *self {
+            Self::Center => 1,
+            Self::Regular {
+                per_axis,
+            }
+            | Self::Jittered {
+                per_axis,
+            } => per_axis * per_axis,
+            Self::Halton {
+                samples,
+            } => samples,
+        }
+    }
+
+    // The `index`th of `count()` sample positions. Only jittered sampling
+    // draws from `rng`.
+    pub fn sample(&self, index: u32, rng: &mut Rng) -> (f32, f32) {
+        match *self {
+            Self::Center => (0.5, 0.5),
+            Self::Regular {
+                per_axis,
+            } => grid_cell(index, per_axis, (0.5, 0.5)),
+            Self::Jittered {
+                per_axis,
+            } => grid_cell(index, per_axis, (rng.next_f32(), rng.next_f32())),
+            // Index 0 would put a sample in the corner of every pixel.
+            Self::Halton {
+                ..
+            } => (radical_inverse(2, index + 1), radical_inverse(3, index + 1)),
+        }
+    }
+}
+
+// The point at `offset` within cell `index` of a `per_axis` square grid,
+// counting along rows.
+fn grid_cell(index: u32, per_axis: u32, offset: (f32, f32)) -> (f32, f32) {
+    let (col, row) = (index % per_axis, index / per_axis);
+    let cell = 1. / per_axis as f32;
+    (
+        (col as f32 + offset.0) * cell,
+        (row as f32 + offset.1) * cell,
+    )
+}
+
+// Mirrors the digits of `index` in `base` about the radix point, so 1, 2, 3
+// in base 2 become 0.5, 0.25, 0.75.
+pub fn radical_inverse(base: u32, mut index: u32) -> f32 {
+    let inv_base = 1. / base as f32;
+    let mut scale = inv_base;
+    let mut result = 0.;
+    while index > 0 {
+        result += (index % base) as f32 * scale;
+        index /= base;
+        scale *= inv_base;
+    }
+    result
+}
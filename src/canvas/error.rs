@@ -0,0 +1,71 @@
+use std::{error::Error, fmt, io};
+
+use crate::compress::InflateError;
+
+// Why an image could not be read.
+#[derive(Debug)]
+pub enum ImageError {
+    Io(io::Error),
+    // The data does not start with the signature of a supported format.
+    UnknownFormat,
+    // The data ends before the header or pixels are complete.
+    Truncated,
+    // A header field is missing or not a valid value.
+    InvalidHeader(&'static str),
+    InvalidDimensions { width: u64, height: u64 },
+    InvalidMaxval(u64),
+    // A sample is malformed or larger than the maxval.
+    InvalidSample { offset: usize },
+    // A valid image using a feature this crate does not read.
+    Unsupported(&'static str),
+    // Part of the file does not match its checksum.
+    ChecksumMismatch,
+    // The file's structure is invalid, such as chunks out of order.
+    Malformed(&'static str),
+    Compression(InflateError),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::UnknownFormat => write!(f, "unknown image format"),
+            Self::Truncated => write!(f, "image data ends unexpectedly"),
+            Self::InvalidHeader(field) => write!(f, "invalid {field} in image header"),
+            Self::InvalidDimensions {
+                width,
+                height,
+            } => write!(f, "invalid image dimensions {width}x{height}"),
+            Self::InvalidMaxval(maxval) => write!(f, "invalid maxval {maxval}"),
+            Self::InvalidSample {
+                offset,
+            } => write!(f, "invalid sample at byte {offset}"),
+            Self::Unsupported(feature) => write!(f, "unsupported image feature: {feature}"),
+            Self::ChecksumMismatch => write!(f, "image data does not match its checksum"),
+            Self::Malformed(reason) => write!(f, "malformed image: {reason}"),
+            Self::Compression(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ImageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Compression(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<InflateError> for ImageError {
+    fn from(err: InflateError) -> Self {
+        Self::Compression(err)
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    io::{self, Read, Write},
+    str,
+};
+
+use crate::{
+    canvas::{Canvas, ImageError},
+    color::Color,
+};
+
+// Scanlines outside these widths cannot be run-length encoded.
+const MIN_RLE_WIDTH: u32 = 8;
+const MAX_RLE_WIDTH: u32 = 0x7FFF;
+// Runs are stored as a count over 128 and the repeated byte, and literal
+// stretches as a count of at most 128 and the bytes themselves.
+const MAX_RUN: usize = 127;
+const MAX_LITERAL: usize = 128;
+// Shorter runs are cheaper to leave inside a literal stretch.
+const MIN_RUN: usize = 4;
+
+impl Canvas {
+    // Writes the canvas as a Radiance HDR image, keeping the full range of
+    // each color instead of clamping it to [0, 1]. Scanlines are run-length
+    // encoded one channel at a time whenever the width allows it.
+    pub fn write_hdr(&self, mut writer: impl Write) -> io::Result<()> {
+        if self.width == 0 || self.height == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "HDR images must be at least 1x1",
+            ));
+        }
+        write!(
+            writer,
+            "#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y {} +X {}\n",
+            self.height, self.width
+        )?;
+
+        let rle = (MIN_RLE_WIDTH..=MAX_RLE_WIDTH).contains(&self.width);
+        let mut pixels = Vec::with_capacity(self.width as usize);
+        let mut channel = Vec::with_capacity(self.width as usize);
+        let mut buffer = Vec::new();
+        for row in self.rows() {
+            pixels.clear();
+            pixels.extend(row.iter().map(Color::to_rgbe));
+
+            buffer.clear();
+            if rle {
+                let [hi, lo] = (self.width as u16).to_be_bytes();
+                buffer.extend([2, 2, hi, lo]);
+                for c in 0..4 {
+                    channel.clear();
+                    channel.extend(pixels.iter().map(|pixel| pixel[c]));
+                    encode_runs(&channel, &mut buffer);
+                }
+            } else {
+                buffer.extend(pixels.iter().flatten());
+            }
+            writer.write_all(&buffer)?;
+        }
+
+        Ok(())
+    }
+
+    // Reads a Radiance HDR image in RGBE with the usual top-to-bottom,
+    // left-to-right orientation. Colors are divided by any EXPOSURE in the
+    // header to give back the radiance that was stored.
+    pub fn read_hdr(mut reader: impl Read) -> Result<Self, ImageError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        if !data.starts_with(b"#?") {
+            return Err(if b"#?".starts_with(&data) {
+                ImageError::Truncated
+            } else {
+                ImageError::UnknownFormat
+            });
+        }
+
+        let mut pos = 0;
+        let mut exposure = 1.;
+        // The header is a list of lines ending with an empty one. Besides
+        // the format and exposure, its lines do not change the pixels.
+        loop {
+            let line = next_line(&data, &mut pos)?;
+            if line.is_empty() {
+                break;
+            }
+            if let Some(format) = line.strip_prefix(b"FORMAT=") {
+                match format.trim_ascii() {
+                    b"32-bit_rle_rgbe" => {}
+                    b"32-bit_rle_xyze" => return Err(ImageError::Unsupported("XYZE colors")),
+                    _ => return Err(ImageError::InvalidHeader("format")),
+                }
+            } else if let Some(value) = line.strip_prefix(b"EXPOSURE=") {
+                exposure *= str::from_utf8(value)
+                    .ok()
+                    .and_then(|s| s.trim().parse::<f32>().ok())
+                    .filter(|&e| e > 0. && e.is_finite())
+                    .ok_or(ImageError::InvalidHeader("exposure"))?;
+            }
+        }
+
+        let (width, height) = resolution(next_line(&data, &mut pos)?)?;
+        let mut pixels = Vec::new();
+        let mut scanline = Vec::new();
+        for _ in 0..height {
+            read_scanline(&data, &mut pos, width, &mut scanline)?;
+            pixels.extend(
+                scanline
+                    .iter()
+                    .map(|&rgbe| Color::from_rgbe(rgbe) * (1. / exposure)),
+            );
+        }
+
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+}
+
+// Appends `data` as runs and literal stretches.
+fn encode_runs(data: &[u8], out: &mut Vec<u8>) {
+    let run_at = |i: usize| {
+        data[i..]
+            .iter()
+            .take(MAX_RUN)
+            .take_while(|&&b| b == data[i])
+            .count()
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        let run = run_at(i);
+        if run >= MIN_RUN {
+            out.extend([128 + run as u8, data[i]]);
+            i += run;
+            continue;
+        }
+
+        let start = i;
+        while i < data.len() && i - start < MAX_LITERAL && (i == start || run_at(i) < MIN_RUN) {
+            i += 1;
+        }
+        out.push((i - start) as u8);
+        out.extend_from_slice(&data[start..i]);
+    }
+}
+
+// The line starting at `pos`, without its newline.
+fn next_line<'a>(data: &'a [u8], pos: &mut usize) -> Result<&'a [u8], ImageError> {
+    let rest = &data[*pos..];
+    let len = rest
+        .iter()
+        .position(|&b| b == b'\n')
+        .ok_or(ImageError::Truncated)?;
+    *pos += len + 1;
+    Ok(&rest[..len])
+}
+
+// The width and height from a resolution line such as `-Y 480 +X 640`.
+fn resolution(line: &[u8]) -> Result<(u32, u32), ImageError> {
+    let fields: Vec<_> = line.split(u8::is_ascii_whitespace).collect();
+    let &[y_axis, height, x_axis, width] = fields.as_slice() else {
+        return Err(ImageError::InvalidHeader("resolution"));
+    };
+    let axis = |field: &[u8]| matches!(field, b"-Y" | b"+Y" | b"-X" | b"+X");
+    if !axis(y_axis) || !axis(x_axis) {
+        return Err(ImageError::InvalidHeader("resolution"));
+    }
+    if (y_axis, x_axis) != (b"-Y".as_slice(), b"+X".as_slice()) {
+        return Err(ImageError::Unsupported("image orientation"));
+    }
+
+    let number = |field: &[u8], name| {
+        str::from_utf8(field)
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .ok_or(ImageError::InvalidHeader(name))
+    };
+    let width = number(width, "width")?;
+    let height = number(height, "height")?;
+    match (u32::try_from(width), u32::try_from(height)) {
+        (Ok(w), Ok(h)) if w > 0 && h > 0 && w.checked_mul(h).is_some() => Ok((w, h)),
+        _ => Err(ImageError::InvalidDimensions {
+            width,
+            height,
+        }),
+    }
+}
+
+// Decodes the scanline at `pos` into `out`. Run-length encoded scanlines
+// start with 2, 2 and their width; anything else is flat RGBE pixels. The
+// older encoding with runs marked by 1, 1, 1 pixels is not recognized.
+fn read_scanline(
+    data: &[u8],
+    pos: &mut usize,
+    width: u32,
+    out: &mut Vec<[u8; 4]>,
+) -> Result<(), ImageError> {
+    let start = data.get(*pos..*pos + 4).ok_or(ImageError::Truncated)?;
+    let mut take = |len: usize| -> Result<&[u8], ImageError> {
+        let bytes = data.get(*pos..*pos + len).ok_or(ImageError::Truncated)?;
+        *pos += len;
+        Ok(bytes)
+    };
+
+    out.clear();
+    let rle = (MIN_RLE_WIDTH..=MAX_RLE_WIDTH).contains(&width)
+        && start[0] == 2
+        && start[1] == 2
+        && start[2] & 0x80 == 0;
+    if !rle {
+        // Checked up front so a bogus width cannot make us allocate a huge
+        // scanline.
+        let bytes = take(width as usize * 4)?;
+        out.extend(bytes.chunks_exact(4).map(|p| [p[0], p[1], p[2], p[3]]));
+        return Ok(());
+    }
+
+    take(4)?;
+    if u32::from(u16::from_be_bytes([start[2], start[3]])) != width {
+        return Err(ImageError::Malformed("scanline width does not match"));
+    }
+    out.resize(width as usize, [0; 4]);
+    for c in 0..4 {
+        let mut x = 0;
+        while x < out.len() {
+            let count = usize::from(take(1)?[0]);
+            let (len, run) = if count > 128 {
+                (count - 128, true)
+            } else {
+                (count, false)
+            };
+            if len == 0 || x + len > out.len() {
+                return Err(ImageError::Malformed("run does not fit its scanline"));
+            }
+
+            let bytes = take(if run { 1 } else { len })?;
+            for (i, pixel) in out[x..x + len].iter_mut().enumerate() {
+                pixel[c] = if run { bytes[0] } else { bytes[i] };
+            }
+            x += len;
+        }
+    }
+
+    Ok(())
+}
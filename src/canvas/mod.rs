@@ -0,0 +1,56 @@
+mod error;
+mod hdr;
+mod png;
+mod ppm;
+
+pub use error::ImageError;
+pub use png::PngFormat;
+
+use crate::color::Color;
+
+#[derive(Debug, Default)]
+pub struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<Color>,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Color::default(); (width * height) as usize],
+        }
+    }
+
+    pub fn write_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if x < self.width && y < self.height {
+            let idx = (y * self.width + x) as usize;
+            self.pixels[idx] = color;
+        }
+    }
+
+    pub fn pixel_at(&self, x: u32, y: u32) -> Color {
+        if x < self.width && y < self.height {
+            let idx = (y * self.width + x) as usize;
+            self.pixels[idx]
+        } else {
+            Color::default()
+        }
+    }
+
+    // Rows of pixels, top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[Color]> {
+        self.pixels.chunks(self.width.max(1) as usize)
+    }
+
+    // The whole canvas as plain-text PPM. `write_p3` and `write_p6` stream
+    // large images instead of holding them in memory twice.
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = Vec::with_capacity((self.width * self.height * 12) as usize);
+        self.write_p3(&mut ppm, 255)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(ppm).expect("PPM output is ASCII")
+    }
+}
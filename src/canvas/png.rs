@@ -0,0 +1,393 @@
+use std::io::{self, Read, Write};
+
+use crate::{
+    canvas::{Canvas, ImageError},
+    color::Color,
+    compress::{crc32, zlib_compress, zlib_decompress},
+};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+// Compressed data is split into IDAT chunks of at most this many bytes.
+const IDAT_SIZE: usize = 64 * 1024;
+
+const GRAY: u8 = 0;
+const RGB: u8 = 2;
+const PALETTE: u8 = 3;
+const GRAY_ALPHA: u8 = 4;
+const RGBA: u8 = 6;
+
+// The layouts `Canvas::write_png` can produce.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PngFormat {
+    #[default]
+    Rgb8,
+    Rgb16,
+    Rgba8,
+    Rgba16,
+}
+
+impl PngFormat {
+    const fn color_type(self) -> u8 {
+        match self {
+            Self::Rgb8 | Self::Rgb16 => RGB,
+            Self::Rgba8 | Self::Rgba16 => RGBA,
+        }
+    }
+
+    const fn bit_depth(self) -> u8 {
+        match self {
+            Self::Rgb8 | Self::Rgba8 => 8,
+            Self::Rgb16 | Self::Rgba16 => 16,
+        }
+    }
+}
+
+impl Canvas {
+    // Writes the canvas as a PNG. A canvas has no transparency, so in the
+    // RGBA formats every pixel is fully opaque.
+    pub fn write_png(&self, mut writer: impl Write, format: PngFormat) -> io::Result<()> {
+        if self.width == 0 || self.height == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "PNG images must be at least 1x1",
+            ));
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend(self.width.to_be_bytes());
+        header.extend(self.height.to_be_bytes());
+        // Compression, filter and interlace methods are all the default.
+        header.extend([format.bit_depth(), format.color_type(), 0, 0, 0]);
+
+        writer.write_all(&SIGNATURE)?;
+        write_chunk(&mut writer, b"IHDR", &header)?;
+        for data in zlib_compress(&self.filtered_scanlines(format)).chunks(IDAT_SIZE) {
+            write_chunk(&mut writer, b"IDAT", data)?;
+        }
+        write_chunk(&mut writer, b"IEND", &[])
+    }
+
+    // Reads a non-interlaced PNG of any color type and bit depth. Canvases
+    // have no transparency, so alpha channels are ignored.
+    pub fn read_png(mut reader: impl Read) -> Result<Self, ImageError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        match data.get(..SIGNATURE.len()) {
+            Some(signature) if signature == SIGNATURE => {}
+            Some(_) => return Err(ImageError::UnknownFormat),
+            None if SIGNATURE.starts_with(&data) => return Err(ImageError::Truncated),
+            None => return Err(ImageError::UnknownFormat),
+        }
+
+        let mut header = None;
+        let mut palette: &[u8] = &[];
+        let mut compressed = Vec::new();
+        let mut pos = SIGNATURE.len();
+        loop {
+            let (kind, body, next) = read_chunk(&data, pos)?;
+            pos = next;
+
+            match (&kind, &header) {
+                (b"IHDR", None) => header = Some(Header::parse(body)?),
+                (_, None) => return Err(ImageError::Malformed("IHDR is not the first chunk")),
+                (b"IHDR", Some(_)) => return Err(ImageError::Malformed("more than one IHDR")),
+                (b"PLTE", _) => palette = body,
+                (b"IDAT", _) => compressed.extend_from_slice(body),
+                (b"IEND", _) => break,
+                // Bit 5 of the first letter marks chunks that are safe to
+                // skip; any other unknown chunk changes how the image reads.
+                _ if kind[0] & 0x20 == 0 => {
+                    return Err(ImageError::Unsupported("unknown critical chunk"));
+                }
+                _ => {}
+            }
+        }
+
+        let header = header.expect("IHDR is checked before any other chunk");
+        if header.color_type == PALETTE && palette.is_empty() {
+            return Err(ImageError::Malformed("palette image without a PLTE chunk"));
+        }
+
+        // Anything past the scanlines the header describes is an error, so
+        // stop there rather than let the data expand without bound.
+        let scanlines = zlib_decompress(&compressed, header.scanlines_len())?;
+        header.decode(&scanlines, palette)
+    }
+
+    // The raw image data: each row of samples preceded by the filter that
+    // best predicts it.
+    fn filtered_scanlines(&self, format: PngFormat) -> Vec<u8> {
+        let alpha = format.color_type() == RGBA;
+        let bytes_per_sample = usize::from(format.bit_depth() / 8);
+        let maxval = if bytes_per_sample == 2 { u16::MAX } else { 255 };
+        let bpp = (3 + usize::from(alpha)) * bytes_per_sample;
+        let row_len = self.width as usize * bpp;
+
+        let mut out = Vec::with_capacity((row_len + 1) * self.height as usize);
+        let mut previous = vec![0; row_len];
+        let mut current = Vec::with_capacity(row_len);
+        let mut candidate = vec![0; row_len];
+        let mut best = vec![0; row_len];
+
+        for row in self.rows() {
+            current.clear();
+            for pixel in row {
+                let [r, g, b] = pixel.quantize(maxval);
+                let samples = [r, g, b, maxval];
+                for sample in &samples[..3 + usize::from(alpha)] {
+                    if bytes_per_sample == 2 {
+                        current.extend(sample.to_be_bytes());
+                    } else {
+                        current.push(*sample as u8);
+                    }
+                }
+            }
+
+            // Pick the filter whose output is smallest taken as signed
+            // bytes, the usual heuristic for what compresses best.
+            let mut best_filter = 0;
+            let mut best_cost = u64::MAX;
+            for filter in 0..5 {
+                apply_filter(filter, &current, &previous, bpp, &mut candidate);
+                let cost = candidate
+                    .iter()
+                    .map(|&byte| u64::from((byte as i8).unsigned_abs()))
+                    .sum();
+                if cost < best_cost {
+                    best_cost = cost;
+                    best_filter = filter;
+                    best.copy_from_slice(&candidate);
+                }
+            }
+
+            out.push(best_filter);
+            out.extend_from_slice(&best);
+            previous.copy_from_slice(&current);
+        }
+
+        out
+    }
+}
+
+fn write_chunk(writer: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len()).expect("chunks are kept small");
+    writer.write_all(&len.to_be_bytes())?;
+
+    let mut checked = Vec::with_capacity(4 + data.len());
+    checked.extend_from_slice(kind);
+    checked.extend_from_slice(data);
+    writer.write_all(&checked)?;
+    writer.write_all(&crc32(&checked).to_be_bytes())
+}
+
+// The chunk at `pos` as its type, its data and where the next one starts.
+fn read_chunk(data: &[u8], pos: usize) -> Result<([u8; 4], &[u8], usize), ImageError> {
+    let header = data.get(pos..pos + 8).ok_or(ImageError::Truncated)?;
+    let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let kind = [header[4], header[5], header[6], header[7]];
+
+    let end = pos + 8 + len;
+    let crc = data.get(end..end + 4).ok_or(ImageError::Truncated)?;
+    // The CRC covers the type and the data, which sit next to each other.
+    if crc32(&data[pos + 4..end]).to_be_bytes() != crc {
+        return Err(ImageError::ChecksumMismatch);
+    }
+    Ok((kind, &data[pos + 8..end], end + 4))
+}
+
+struct Header {
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: u8,
+}
+
+impl Header {
+    fn parse(body: &[u8]) -> Result<Self, ImageError> {
+        let &[
+            w0,
+            w1,
+            w2,
+            w3,
+            h0,
+            h1,
+            h2,
+            h3,
+            bit_depth,
+            color_type,
+            compression,
+            filter,
+            interlace,
+        ] = body
+        else {
+            return Err(ImageError::Malformed("IHDR must be 13 bytes"));
+        };
+        let width = u32::from_be_bytes([w0, w1, w2, w3]);
+        let height = u32::from_be_bytes([h0, h1, h2, h3]);
+
+        if width == 0 || height == 0 || width.checked_mul(height).is_none() {
+            return Err(ImageError::InvalidDimensions {
+                width: width.into(),
+                height: height.into(),
+            });
+        }
+        let valid_depth = match color_type {
+            GRAY => matches!(bit_depth, 1 | 2 | 4 | 8 | 16),
+            PALETTE => matches!(bit_depth, 1 | 2 | 4 | 8),
+            RGB | GRAY_ALPHA | RGBA => matches!(bit_depth, 8 | 16),
+            _ => false,
+        };
+        if !valid_depth {
+            return Err(ImageError::Malformed("invalid color type and bit depth"));
+        }
+        if compression != 0 || filter != 0 {
+            return Err(ImageError::Malformed(
+                "unknown compression or filter method",
+            ));
+        }
+        match interlace {
+            0 => {}
+            1 => return Err(ImageError::Unsupported("interlaced PNG")),
+            _ => return Err(ImageError::Malformed("unknown interlace method")),
+        }
+
+        Ok(Self {
+            width,
+            height,
+            bit_depth,
+            color_type,
+        })
+    }
+
+    const fn channels(&self) -> usize {
+        match self.color_type {
+            GRAY | PALETTE => 1,
+            GRAY_ALPHA => 2,
+            RGB => 3,
+            _ => 4,
+        }
+    }
+
+    const fn bits_per_pixel(&self) -> usize {
+        self.channels() * self.bit_depth as usize
+    }
+
+    // Bytes in a row of samples, without its filter byte.
+    const fn row_len(&self) -> usize {
+        (self.width as usize * self.bits_per_pixel()).div_ceil(8)
+    }
+
+    // Bytes in the decompressed image data, each row led by its filter.
+    const fn scanlines_len(&self) -> usize {
+        (self.row_len() + 1) * self.height as usize
+    }
+
+    fn decode(&self, scanlines: &[u8], palette: &[u8]) -> Result<Canvas, ImageError> {
+        let channels = self.channels();
+        // Filters work on whole bytes, comparing each with the same byte of
+        // the pixel before, or just the byte before for packed pixels.
+        let bpp = self.bits_per_pixel().div_ceil(8);
+        let row_len = self.row_len();
+        if scanlines.len() < self.scanlines_len() {
+            return Err(ImageError::Truncated);
+        }
+
+        let mut canvas = Canvas::new(self.width, self.height);
+        let maxval = ((1_u32 << self.bit_depth) - 1) as f32;
+        let mut previous = vec![0; row_len];
+        let mut current = vec![0; row_len];
+
+        for (pixels, line) in canvas
+            .pixels
+            .chunks_mut(self.width as usize)
+            .zip(scanlines.chunks(row_len + 1))
+        {
+            let filter = line[0];
+            if filter > 4 {
+                return Err(ImageError::Malformed("unknown filter type"));
+            }
+            undo_filter(filter, &line[1..], &previous, bpp, &mut current);
+
+            for (x, pixel) in pixels.iter_mut().enumerate() {
+                let sample = |channel| self.sample(&current, x * channels + channel);
+                let value = |channel| sample(channel) as f32 / maxval;
+                *pixel = match self.color_type {
+                    GRAY | GRAY_ALPHA => Color::new(value(0), value(0), value(0)),
+                    PALETTE => {
+                        let idx = sample(0) as usize * 3;
+                        let rgb = palette
+                            .get(idx..idx + 3)
+                            .ok_or(ImageError::Malformed("palette index out of range"))?;
+                        let channel = |c: u8| f32::from(c) / 255.;
+                        Color::new(channel(rgb[0]), channel(rgb[1]), channel(rgb[2]))
+                    }
+                    _ => Color::new(value(0), value(1), value(2)),
+                };
+            }
+
+            std::mem::swap(&mut previous, &mut current);
+        }
+
+        Ok(canvas)
+    }
+
+    // The `idx`th sample of an unfiltered row. Samples under 8 bits are
+    // packed most significant first.
+    fn sample(&self, row: &[u8], idx: usize) -> u16 {
+        match self.bit_depth {
+            16 => u16::from_be_bytes([row[idx * 2], row[idx * 2 + 1]]),
+            8 => row[idx].into(),
+            depth => {
+                let bit = idx * usize::from(depth);
+                let shift = 8 - usize::from(depth) - bit % 8;
+                ((row[bit / 8] >> shift) & ((1 << depth) - 1)).into()
+            }
+        }
+    }
+}
+
+// Predicts a byte from the ones to its left (`a`), above (`b`) and above
+// left (`c`), picking whichever is closest to `a + b - c`.
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = i16::from(a) + i16::from(b) - i16::from(c);
+    let (pa, pb, pc) = (
+        (p - i16::from(a)).abs(),
+        (p - i16::from(b)).abs(),
+        (p - i16::from(c)).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+// The prediction for byte `i` under `filter`, given the already known bytes
+// of this row and the previous one.
+fn predict(filter: u8, row: &[u8], previous: &[u8], bpp: usize, i: usize) -> u8 {
+    let a = if i >= bpp { row[i - bpp] } else { 0 };
+    let b = previous[i];
+    let c = if i >= bpp { previous[i - bpp] } else { 0 };
+    match filter {
+        1 => a,
+        2 => b,
+        3 => ((u16::from(a) + u16::from(b)) / 2) as u8,
+        4 => paeth(a, b, c),
+        _ => 0,
+    }
+}
+
+fn apply_filter(filter: u8, row: &[u8], previous: &[u8], bpp: usize, out: &mut [u8]) {
+    for i in 0..row.len() {
+        out[i] = row[i].wrapping_sub(predict(filter, row, previous, bpp, i));
+    }
+}
+
+fn undo_filter(filter: u8, line: &[u8], previous: &[u8], bpp: usize, out: &mut [u8]) {
+    for i in 0..line.len() {
+        out[i] = line[i].wrapping_add(predict(filter, out, previous, bpp, i));
+    }
+}
@@ -0,0 +1,368 @@
+use std::{
+    io::{self, Read, Write},
+    str,
+};
+
+use crate::{
+    canvas::{Canvas, ImageError},
+    color::Color,
+};
+
+// Plain PPM caps lines at this many characters.
+const P3_LINE_WIDTH: usize = 70;
+
+impl Canvas {
+    // Streams the canvas as plain-text PPM (P3), one row at a time. Channels
+    // are scaled to `0..=maxval` and lines wrap at 70 characters.
+    pub fn write_p3(&self, mut writer: impl Write, maxval: u16) -> io::Result<()> {
+        write_header(&mut writer, "P3", self, maxval)?;
+
+        let mut line = String::with_capacity(P3_LINE_WIDTH + 1);
+        for row in self.rows() {
+            for value in row.iter().flat_map(|pixel| pixel.quantize(maxval)) {
+                let value = value.to_string();
+                if !line.is_empty() && line.len() + 1 + value.len() > P3_LINE_WIDTH {
+                    line.push('\n');
+                    writer.write_all(line.as_bytes())?;
+                    line.clear();
+                }
+
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(&value);
+            }
+
+            // Every row starts on a new line.
+            if !line.is_empty() {
+                line.push('\n');
+                writer.write_all(line.as_bytes())?;
+                line.clear();
+            }
+        }
+
+        writer.write_all(b"\n")
+    }
+
+    // Streams the canvas as binary PPM (P6), one row at a time. Channels take
+    // one byte each when `maxval` fits in a byte and two big-endian bytes
+    // otherwise.
+    pub fn write_p6(&self, mut writer: impl Write, maxval: u16) -> io::Result<()> {
+        write_header(&mut writer, "P6", self, maxval)?;
+
+        let bytes_per_channel = if maxval > 255 { 2 } else { 1 };
+        let mut buffer = Vec::with_capacity(self.width as usize * 3 * bytes_per_channel);
+        for row in self.rows() {
+            buffer.clear();
+            for value in row.iter().flat_map(|pixel| pixel.quantize(maxval)) {
+                if bytes_per_channel == 2 {
+                    buffer.extend_from_slice(&value.to_be_bytes());
+                } else {
+                    buffer.push(value as u8);
+                }
+            }
+            writer.write_all(&buffer)?;
+        }
+
+        Ok(())
+    }
+
+    // Writes the canvas as a color PFM, keeping every value as it is
+    // instead of clamping to [0, 1]. Samples are little-endian floats and
+    // rows run bottom to top.
+    pub fn write_pfm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "PF\n{} {}\n-1.0\n", self.width, self.height)?;
+
+        let mut buffer = Vec::with_capacity(self.width as usize * 12);
+        for row in self.rows().rev() {
+            buffer.clear();
+            for pixel in row {
+                for value in [pixel.r, pixel.g, pixel.b] {
+                    buffer.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            writer.write_all(&buffer)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_header(
+    writer: &mut impl Write,
+    magic: &str,
+    canvas: &Canvas,
+    maxval: u16,
+) -> io::Result<()> {
+    if maxval == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "PPM maxval must be at least 1",
+        ));
+    }
+    write!(
+        writer,
+        "{magic}\n{} {}\n{maxval}\n",
+        canvas.width, canvas.height
+    )
+}
+
+impl Canvas {
+    // Reads any Netpbm image, P1 to P6, or a PFM image (`PF` for color, `Pf`
+    // for grayscale). Integer samples are scaled from `0..=maxval` into
+    // [0, 1]. PFM samples are kept as they are, beyond 1 included; the scale
+    // in a PFM header only gives their byte order.
+    pub fn read_ppm(mut reader: impl Read) -> Result<Self, ImageError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        let mut parser = Parser {
+            data: &data,
+            pos: 0,
+        };
+        let magic = parser.take(2)?;
+        match magic {
+            b"P1" | b"P4" => read_bitmap(&mut parser, magic == b"P4"),
+            b"P2" | b"P5" => read_graymap(&mut parser, 1, magic == b"P5"),
+            b"P3" | b"P6" => read_graymap(&mut parser, 3, magic == b"P6"),
+            b"Pf" => read_pfm(&mut parser, 1),
+            b"PF" => read_pfm(&mut parser, 3),
+            _ => Err(ImageError::UnknownFormat),
+        }
+    }
+}
+
+// A position in the image data. Headers are whitespace-separated ASCII
+// tokens, and a `#` starts a comment running to the end of the line.
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some(&byte) = self.data.get(self.pos) {
+            if byte == b'#' {
+                while self.data.get(self.pos).is_some_and(|&b| b != b'\n') {
+                    self.pos += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn token(&mut self) -> Result<&'a [u8], ImageError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .data
+            .get(self.pos)
+            .is_some_and(|&b| !b.is_ascii_whitespace() && b != b'#')
+        {
+            self.pos += 1;
+        }
+
+        if start == self.pos {
+            return Err(ImageError::Truncated);
+        }
+        Ok(&self.data[start..self.pos])
+    }
+
+    fn header_field<T: str::FromStr>(&mut self, field: &'static str) -> Result<T, ImageError> {
+        let token = self.token()?;
+        str::from_utf8(token)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or(ImageError::InvalidHeader(field))
+    }
+
+    // Width and height, checked to be non-zero and to fit a canvas.
+    fn dimensions(&mut self) -> Result<(u32, u32), ImageError> {
+        let width: u64 = self.header_field("width")?;
+        let height: u64 = self.header_field("height")?;
+        match (u32::try_from(width), u32::try_from(height)) {
+            (Ok(w), Ok(h)) if w > 0 && h > 0 && w.checked_mul(h).is_some() => Ok((w, h)),
+            _ => Err(ImageError::InvalidDimensions {
+                width,
+                height,
+            }),
+        }
+    }
+
+    fn maxval(&mut self) -> Result<u16, ImageError> {
+        let maxval: u64 = self.header_field("maxval")?;
+        u16::try_from(maxval)
+            .ok()
+            .filter(|&maxval| maxval > 0)
+            .ok_or(ImageError::InvalidMaxval(maxval))
+    }
+
+    // Binary data starts after exactly one whitespace byte, since the data
+    // itself may begin with bytes that look like whitespace.
+    fn end_binary_header(&mut self) -> Result<(), ImageError> {
+        match self.data.get(self.pos) {
+            Some(byte) if byte.is_ascii_whitespace() => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(_) => Err(ImageError::InvalidHeader("separator")),
+            None => Err(ImageError::Truncated),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ImageError> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or(ImageError::Truncated)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    // Fails early when the data is too short to hold `len` more bytes, so
+    // that a bogus header cannot make us allocate a huge canvas.
+    fn expect_at_least(&self, len: u64) -> Result<(), ImageError> {
+        if ((self.data.len() - self.pos) as u64) < len {
+            return Err(ImageError::Truncated);
+        }
+        Ok(())
+    }
+
+    fn ascii_sample(&mut self) -> Result<u16, ImageError> {
+        self.skip_whitespace();
+        let offset = self.pos;
+        let token = self.token()?;
+        str::from_utf8(token)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or(ImageError::InvalidSample {
+                offset,
+            })
+    }
+}
+
+// PBM: one bit per pixel, where 1 is black. Plain bitmaps may run the digits
+// together, and each row of a raw bitmap is padded to a whole byte.
+fn read_bitmap(parser: &mut Parser, binary: bool) -> Result<Canvas, ImageError> {
+    let (width, height) = parser.dimensions()?;
+    let row_len = width.div_ceil(8) as usize;
+    if binary {
+        parser.end_binary_header()?;
+        parser.expect_at_least(row_len as u64 * u64::from(height))?;
+    } else {
+        parser.expect_at_least(u64::from(width) * u64::from(height))?;
+    }
+
+    let mut canvas = Canvas::new(width, height);
+    for row in canvas.pixels.chunks_mut(width as usize) {
+        let bytes = if binary { parser.take(row_len)? } else { &[] };
+
+        for (x, pixel) in row.iter_mut().enumerate() {
+            let black = if binary {
+                bytes[x / 8] & (0x80 >> (x % 8)) != 0
+            } else {
+                parser.skip_whitespace();
+                let offset = parser.pos;
+                match parser.take(1)? {
+                    b"0" => false,
+                    b"1" => true,
+                    _ => {
+                        return Err(ImageError::InvalidSample {
+                            offset,
+                        });
+                    }
+                }
+            };
+            *pixel = if black { Color::BLACK } else { Color::WHITE };
+        }
+    }
+
+    Ok(canvas)
+}
+
+// PGM (one channel) and PPM (three channels), as text or raw bytes. Raw
+// samples take two big-endian bytes when the maxval is over 255.
+fn read_graymap(parser: &mut Parser, channels: usize, binary: bool) -> Result<Canvas, ImageError> {
+    let (width, height) = parser.dimensions()?;
+    let maxval = parser.maxval()?;
+    let bytes_per_sample = if maxval > 255 { 2 } else { 1 };
+    let samples = u64::from(width) * u64::from(height) * channels as u64;
+    if binary {
+        parser.end_binary_header()?;
+        parser.expect_at_least(samples * bytes_per_sample as u64)?;
+    } else {
+        parser.expect_at_least(samples)?;
+    }
+
+    let mut canvas = Canvas::new(width, height);
+    let scale = 1. / f32::from(maxval);
+    let mut channel = [0.; 3];
+    for pixel in &mut canvas.pixels {
+        for value in &mut channel[..channels] {
+            if !binary {
+                parser.skip_whitespace();
+            }
+            let offset = parser.pos;
+            let sample = if !binary {
+                parser.ascii_sample()?
+            } else if bytes_per_sample == 2 {
+                let bytes = parser.take(2)?;
+                u16::from_be_bytes([bytes[0], bytes[1]])
+            } else {
+                u16::from(parser.take(1)?[0])
+            };
+
+            if sample > maxval {
+                return Err(ImageError::InvalidSample {
+                    offset,
+                });
+            }
+            *value = f32::from(sample) * scale;
+        }
+
+        *pixel = match channels {
+            1 => Color::new(channel[0], channel[0], channel[0]),
+            _ => Color::new(channel[0], channel[1], channel[2]),
+        };
+    }
+
+    Ok(canvas)
+}
+
+// PFM: raw 32-bit floats, little-endian when the scale is negative, with the
+// bottom row first.
+fn read_pfm(parser: &mut Parser, channels: usize) -> Result<Canvas, ImageError> {
+    let (width, height) = parser.dimensions()?;
+    let scale: f32 = parser.header_field("scale")?;
+    if scale == 0. || !scale.is_finite() {
+        return Err(ImageError::InvalidHeader("scale"));
+    }
+    parser.end_binary_header()?;
+    parser.expect_at_least(u64::from(width) * u64::from(height) * channels as u64 * 4)?;
+
+    let little_endian = scale < 0.;
+    let mut canvas = Canvas::new(width, height);
+    let mut channel = [0.; 3];
+    for row in canvas.pixels.chunks_mut(width as usize).rev() {
+        for pixel in row {
+            for value in &mut channel[..channels] {
+                let bytes: [u8; 4] = parser.take(4)?.try_into().expect("took 4 bytes");
+                *value = if little_endian {
+                    f32::from_le_bytes(bytes)
+                } else {
+                    f32::from_be_bytes(bytes)
+                };
+            }
+
+            *pixel = match channels {
+                1 => Color::new(channel[0], channel[0], channel[0]),
+                _ => Color::new(channel[0], channel[1], channel[2]),
+            };
+        }
+    }
+
+    Ok(canvas)
+}
@@ -10,6 +10,9 @@ pub struct Color {
 }
 
 impl Color {
+    pub const BLACK: Self = Self::new(0., 0., 0.);
+    pub const WHITE: Self = Self::new(1., 1., 1.);
+
     pub const fn new(r: f32, g: f32, b: f32) -> Self {
         Self {
             r,
@@ -23,9 +26,51 @@ impl Color {
     }
 
     pub fn to_rgb8(&self) -> [u8; 3] {
-        let scale = |c: f32| (c.clamp(0., 1.) * 255.0).round() as u8;
+        self.quantize(255).map(|c| c as u8)
+    }
+
+    // Each channel clamped to [0, 1] and scaled to `0..=maxval`.
+    pub fn quantize(&self, maxval: u16) -> [u16; 3] {
+        let scale = |c: f32| (c.clamp(0., 1.) * f32::from(maxval)).round() as u16;
         [scale(self.r), scale(self.g), scale(self.b)]
     }
+
+    // Radiance's shared-exponent encoding: an 8-bit mantissa per channel and
+    // one exponent, biased by 128, sized for the brightest channel. Negative
+    // and NaN channels become 0, and anything too bright saturates.
+    pub fn to_rgbe(&self) -> [u8; 4] {
+        let channels = [self.r, self.g, self.b].map(|c| f64::from(c.max(0.)));
+        let brightest = channels.iter().copied().fold(0., f64::max);
+        if brightest < 2_f64.powi(-128) {
+            return [0; 4];
+        }
+        if brightest.is_infinite() {
+            return [255; 4];
+        }
+
+        // `brightest` is `m * 2^exponent` with m in [0.5, 1).
+        let mut exponent = brightest.log2().floor() as i32 + 1;
+        let mut scale = 256. / 2_f64.powi(exponent);
+        if (brightest * scale).round() >= 256. {
+            exponent += 1;
+            scale /= 2.;
+        }
+        if exponent > 127 {
+            return [255; 4];
+        }
+
+        let [r, g, b] = channels.map(|c| (c * scale).round().min(255.) as u8);
+        [r, g, b, (exponent + 128) as u8]
+    }
+
+    pub fn from_rgbe([r, g, b, e]: [u8; 4]) -> Self {
+        if e == 0 {
+            return Self::BLACK;
+        }
+        let scale = 2_f64.powi(i32::from(e) - 128 - 8);
+        let channel = |c: u8| (f64::from(c) * scale) as f32;
+        Self::new(channel(r), channel(g), channel(b))
+    }
 }
 
 impl Add for Color {
@@ -0,0 +1,192 @@
+use super::{DIST_BASE, DIST_EXTRA, LENGTH_BASE, LENGTH_EXTRA, fixed_literal_lengths};
+
+const WINDOW_SIZE: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: u32 = 15;
+// How many earlier positions with the same hash to try before settling for
+// the best match so far.
+const MAX_CHAIN: usize = 64;
+
+// Writes bits least significant first, as DEFLATE packs them.
+struct BitWriter {
+    out: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        self.buffer |= u64::from(value) << self.count;
+        self.count += bits;
+        while self.count >= 8 {
+            self.out.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    // Huffman codes are defined most significant bit first, the other way
+    // round from everything else in the stream.
+    fn write_code(&mut self, code: u16, len: u8) {
+        let reversed = code.reverse_bits() >> (16 - len);
+        self.write(u32::from(reversed), u32::from(len));
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.out.push(self.buffer as u8);
+        }
+        self.out
+    }
+}
+
+// Canonical codes for the given code lengths (RFC 1951, section 3.2.2).
+fn canonical_codes<const N: usize>(lengths: &[u8; N]) -> [u16; N] {
+    let mut counts = [0_u16; 16];
+    lengths
+        .iter()
+        .for_each(|&len| counts[usize::from(len)] += 1);
+    counts[0] = 0;
+
+    let mut next = [0_u16; 16];
+    let mut code = 0;
+    for len in 1..16 {
+        code = (code + counts[len - 1]) << 1;
+        next[len] = code;
+    }
+
+    let mut codes = [0; N];
+    for (code, &len) in codes.iter_mut().zip(lengths) {
+        if len != 0 {
+            *code = next[usize::from(len)];
+            next[usize::from(len)] += 1;
+        }
+    }
+    codes
+}
+
+// Compresses `data` into a single DEFLATE block using the fixed Huffman
+// codes, with repeats found by a greedy LZ77 search over hash chains. The
+// fixed codes save building and sending per-image tables, at some cost in
+// size next to zlib.
+pub fn deflate(data: &[u8]) -> Vec<u8> {
+    let literal_lengths = fixed_literal_lengths();
+    let literal_codes = canonical_codes(&literal_lengths);
+    let literal = |writer: &mut BitWriter, symbol: usize| {
+        writer.write_code(literal_codes[symbol], literal_lengths[symbol]);
+    };
+
+    let mut writer = BitWriter {
+        out: Vec::with_capacity(data.len() / 2 + 16),
+        buffer: 0,
+        count: 0,
+    };
+    // Final block, fixed codes.
+    writer.write(1, 1);
+    writer.write(1, 2);
+
+    let mut matcher = Matcher::new(data);
+    let mut pos = 0;
+    while pos < data.len() {
+        let (length, distance) = matcher.longest_match(pos);
+
+        if length >= MIN_MATCH {
+            let idx = LENGTH_BASE.partition_point(|&base| usize::from(base) <= length) - 1;
+            literal(&mut writer, 257 + idx);
+            writer.write(
+                (length - usize::from(LENGTH_BASE[idx])) as u32,
+                u32::from(LENGTH_EXTRA[idx]),
+            );
+
+            let idx = DIST_BASE.partition_point(|&base| usize::from(base) <= distance) - 1;
+            writer.write_code(idx as u16, 5);
+            writer.write(
+                (distance - usize::from(DIST_BASE[idx])) as u32,
+                u32::from(DIST_EXTRA[idx]),
+            );
+
+            (pos..pos + length).for_each(|p| matcher.insert(p));
+            pos += length;
+        } else {
+            literal(&mut writer, usize::from(data[pos]));
+            matcher.insert(pos);
+            pos += 1;
+        }
+    }
+
+    literal(&mut writer, 256);
+    writer.finish()
+}
+
+// Earlier positions in the data, chained by the hash of the three bytes
+// starting there.
+struct Matcher<'a> {
+    data: &'a [u8],
+    // The most recent position with each hash, or `usize::MAX` for none.
+    head: Vec<usize>,
+    // For each position in the window, the previous one with the same hash.
+    prev: Vec<usize>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            head: vec![usize::MAX; 1 << HASH_BITS],
+            prev: vec![usize::MAX; WINDOW_SIZE],
+        }
+    }
+
+    fn hash(&self, pos: usize) -> usize {
+        let bytes = u32::from_le_bytes([self.data[pos], self.data[pos + 1], self.data[pos + 2], 0]);
+        (bytes.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize
+    }
+
+    fn insert(&mut self, pos: usize) {
+        if pos + MIN_MATCH <= self.data.len() {
+            let h = self.hash(pos);
+            self.prev[pos % WINDOW_SIZE] = self.head[h];
+            self.head[h] = pos;
+        }
+    }
+
+    // The longest earlier repeat of the bytes at `pos` within the window, as
+    // (length, distance), or a length of zero if there is none.
+    fn longest_match(&self, pos: usize) -> (usize, usize) {
+        if pos + MIN_MATCH > self.data.len() {
+            return (0, 0);
+        }
+
+        let max_len = MAX_MATCH.min(self.data.len() - pos);
+        let mut best = (0, 0);
+        let mut candidate = self.head[self.hash(pos)];
+
+        for _ in 0..MAX_CHAIN {
+            if candidate == usize::MAX || pos - candidate > WINDOW_SIZE {
+                break;
+            }
+
+            let len = self.data[candidate..]
+                .iter()
+                .zip(&self.data[pos..pos + max_len])
+                .take_while(|(a, b)| a == b)
+                .count();
+            if len > best.0 {
+                best = (len, pos - candidate);
+                if len == max_len {
+                    break;
+                }
+            }
+
+            // Chains only ever point backwards; anything else is a slot that
+            // has since been reused for a newer position.
+            let next = self.prev[candidate % WINDOW_SIZE];
+            if next >= candidate {
+                break;
+            }
+            candidate = next;
+        }
+        best
+    }
+}
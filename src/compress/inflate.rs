@@ -0,0 +1,266 @@
+use super::{
+    DIST_BASE, DIST_EXTRA, InflateError, LENGTH_BASE, LENGTH_EXTRA, MAX_EXPANSION,
+    fixed_literal_lengths,
+};
+
+const MAX_BITS: usize = 15;
+
+// Reads bits least significant first, as DEFLATE packs them.
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    bit: u32,
+}
+
+impl BitReader<'_> {
+    fn bits(&mut self, count: u32) -> Result<u32, InflateError> {
+        let mut value = 0;
+        for i in 0..count {
+            let byte = *self.data.get(self.pos).ok_or(InflateError::Truncated)?;
+            value |= u32::from((byte >> self.bit) & 1) << i;
+            self.bit += 1;
+            if self.bit == 8 {
+                self.bit = 0;
+                self.pos += 1;
+            }
+        }
+        Ok(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        if self.bit != 0 {
+            self.bit = 0;
+            self.pos += 1;
+        }
+    }
+
+    // Bytes consumed so far, counting a partly read one.
+    fn consumed(&self) -> usize {
+        self.pos + usize::from(self.bit != 0)
+    }
+}
+
+// A canonical Huffman code, stored as the number of codes of each length
+// and the symbols ordered by code. This is the decoding scheme of zlib's
+// `puff`: slow next to table lookups but tiny and easy to check.
+struct Huffman {
+    counts: [u16; MAX_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, InflateError> {
+        let mut counts = [0; MAX_BITS + 1];
+        lengths
+            .iter()
+            .for_each(|&len| counts[usize::from(len)] += 1);
+        counts[0] = 0;
+
+        // Reject codes with more symbols of some length than fit. Incomplete
+        // codes are allowed, as a distance code may hold a single symbol.
+        let mut left = 1_i32;
+        for &count in &counts[1..] {
+            left = (left << 1) - i32::from(count);
+            if left < 0 {
+                return Err(InflateError::InvalidCodeLengths);
+            }
+        }
+
+        let mut offsets = [0; MAX_BITS + 2];
+        for len in 1..=MAX_BITS {
+            offsets[len + 1] = offsets[len] + counts[len];
+        }
+        let mut symbols = vec![0; usize::from(offsets[MAX_BITS + 1])];
+        for (symbol, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                let offset = &mut offsets[usize::from(len)];
+                symbols[usize::from(*offset)] = symbol as u16;
+                *offset += 1;
+            }
+        }
+
+        Ok(Self {
+            counts,
+            symbols,
+        })
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, InflateError> {
+        // `code` is the bits read so far, `first` the first code of the
+        // current length and `index` the position of that code's symbol.
+        let (mut code, mut first, mut index) = (0_i32, 0_i32, 0_i32);
+        for &count in &self.counts[1..] {
+            code |= reader.bits(1)? as i32;
+            let count = i32::from(count);
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(InflateError::InvalidSymbol)
+    }
+}
+
+// Decompresses a raw DEFLATE stream, returning the data and how many bytes of
+// `data` it took up. Fails as soon as the output would grow past `limit`
+// bytes, so a small stream cannot expand without bound.
+pub fn inflate(data: &[u8], limit: usize) -> Result<(Vec<u8>, usize), InflateError> {
+    let mut reader = BitReader {
+        data,
+        pos: 0,
+        bit: 0,
+    };
+    // The output can never be larger than this, however big `limit` is.
+    let most = data.len().saturating_mul(MAX_EXPANSION);
+    let mut out = Vec::with_capacity(limit.min(most));
+
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => stored_block(&mut reader, &mut out, limit)?,
+            1 => {
+                let literals = Huffman::new(&fixed_literal_lengths())?;
+                let distances = Huffman::new(&[5; 30])?;
+                compressed_block(&mut reader, &mut out, limit, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut reader)?;
+                compressed_block(&mut reader, &mut out, limit, &literals, &distances)?;
+            }
+            _ => return Err(InflateError::InvalidBlockType),
+        }
+
+        if last {
+            return Ok((out, reader.consumed()));
+        }
+    }
+}
+
+fn stored_block(
+    reader: &mut BitReader,
+    out: &mut Vec<u8>,
+    limit: usize,
+) -> Result<(), InflateError> {
+    reader.align_to_byte();
+    let header = reader
+        .data
+        .get(reader.pos..reader.pos + 4)
+        .ok_or(InflateError::Truncated)?;
+    let len = u16::from_le_bytes([header[0], header[1]]);
+    let nlen = u16::from_le_bytes([header[2], header[3]]);
+    if len != !nlen {
+        return Err(InflateError::InvalidStoredLength);
+    }
+
+    if out.len() + usize::from(len) > limit {
+        return Err(InflateError::TooLarge);
+    }
+
+    let start = reader.pos + 4;
+    let bytes = reader
+        .data
+        .get(start..start + usize::from(len))
+        .ok_or(InflateError::Truncated)?;
+    out.extend_from_slice(bytes);
+    reader.pos = start + usize::from(len);
+    Ok(())
+}
+
+// The literal/length and distance codes of a dynamic block, which are
+// themselves sent as code lengths compressed with a third Huffman code.
+fn dynamic_codes(reader: &mut BitReader) -> Result<(Huffman, Huffman), InflateError> {
+    const ORDER: [usize; 19] = [
+        16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+    ];
+
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let length_count = reader.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        return Err(InflateError::InvalidCodeLengths);
+    }
+
+    let mut length_lengths = [0; 19];
+    for &idx in &ORDER[..length_count] {
+        length_lengths[idx] = reader.bits(3)? as u8;
+    }
+    let length_code = Huffman::new(&length_lengths)?;
+
+    let mut lengths = vec![0; literal_count + distance_count];
+    let mut idx = 0;
+    while idx < lengths.len() {
+        let symbol = length_code.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *idx
+                    .checked_sub(1)
+                    .and_then(|prev| lengths.get(prev))
+                    .ok_or(InflateError::InvalidCodeLengths)?;
+                (previous, 3 + reader.bits(2)? as usize)
+            }
+            17 => (0, 3 + reader.bits(3)? as usize),
+            _ => (0, 11 + reader.bits(7)? as usize),
+        };
+        if idx + repeat > lengths.len() {
+            return Err(InflateError::InvalidCodeLengths);
+        }
+        lengths[idx..idx + repeat].fill(value);
+        idx += repeat;
+    }
+
+    // Without an end-of-block code the block could never finish.
+    if lengths[256] == 0 {
+        return Err(InflateError::InvalidCodeLengths);
+    }
+    let (literal_lengths, distance_lengths) = lengths.split_at(literal_count);
+    Ok((
+        Huffman::new(literal_lengths)?,
+        Huffman::new(distance_lengths)?,
+    ))
+}
+
+fn compressed_block(
+    reader: &mut BitReader,
+    out: &mut Vec<u8>,
+    limit: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<(), InflateError> {
+    loop {
+        let symbol = literals.decode(reader)?;
+        match symbol {
+            0..=255 if out.len() == limit => return Err(InflateError::TooLarge),
+            0..=255 => out.push(symbol as u8),
+            256 => return Ok(()),
+            257..=285 => {
+                let idx = usize::from(symbol - 257);
+                let length = usize::from(LENGTH_BASE[idx])
+                    + reader.bits(u32::from(LENGTH_EXTRA[idx]))? as usize;
+
+                let idx = usize::from(distances.decode(reader)?);
+                if idx >= DIST_BASE.len() {
+                    return Err(InflateError::InvalidSymbol);
+                }
+                let distance =
+                    usize::from(DIST_BASE[idx]) + reader.bits(u32::from(DIST_EXTRA[idx]))? as usize;
+                if distance > out.len() {
+                    return Err(InflateError::InvalidDistance);
+                }
+                if out.len() + length > limit {
+                    return Err(InflateError::TooLarge);
+                }
+
+                // The source may overlap what is being written, which is how
+                // runs are encoded, so copy a byte at a time.
+                let start = out.len() - distance;
+                for i in 0..length {
+                    out.push(out[start + i]);
+                }
+            }
+            _ => return Err(InflateError::InvalidSymbol),
+        }
+    }
+}
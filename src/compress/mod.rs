@@ -0,0 +1,117 @@
+// Just enough compression support for the image formats in `canvas`, so the
+// crate needs no dependencies: the zlib format (RFC 1950) around DEFLATE
+// (RFC 1951), plus the checksums that PNG and zlib use.
+
+mod checksum;
+mod deflate;
+mod inflate;
+
+use std::{error::Error, fmt};
+
+pub use checksum::{adler32, crc32};
+pub use deflate::deflate;
+pub use inflate::inflate;
+
+// zlib header for a 32 KiB window and no preset dictionary; the second byte
+// makes the pair a multiple of 31, as the format requires.
+const ZLIB_HEADER: [u8; 2] = [0x78, 0x01];
+
+pub fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    let mut out = ZLIB_HEADER.to_vec();
+    out.extend(deflate(data));
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+// Decompresses a zlib stream of at most `limit` bytes once decompressed.
+pub fn zlib_decompress(data: &[u8], limit: usize) -> Result<Vec<u8>, InflateError> {
+    let [cmf, flg, ..] = *data else {
+        return Err(InflateError::Truncated);
+    };
+    let method = cmf & 0x0F;
+    let window_bits = (cmf >> 4) + 8;
+    if method != 8 || window_bits > 15 || (u16::from(cmf) << 8 | u16::from(flg)) % 31 != 0 {
+        return Err(InflateError::InvalidHeader);
+    }
+    if flg & 0x20 != 0 {
+        return Err(InflateError::PresetDictionary);
+    }
+
+    let (out, used) = inflate(&data[2..], limit)?;
+    let checksum = data
+        .get(2 + used..2 + used + 4)
+        .ok_or(InflateError::Truncated)?;
+    if adler32(&out).to_be_bytes() != checksum {
+        return Err(InflateError::ChecksumMismatch);
+    }
+    Ok(out)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InflateError {
+    // The stream ends before its final block does.
+    Truncated,
+    InvalidHeader,
+    PresetDictionary,
+    InvalidBlockType,
+    // A stored block's length does not match its complement.
+    InvalidStoredLength,
+    // Code lengths that do not describe a usable Huffman code.
+    InvalidCodeLengths,
+    InvalidSymbol,
+    // A back-reference to before the start of the output.
+    InvalidDistance,
+    // The data decompresses to more than the caller allowed.
+    TooLarge,
+    ChecksumMismatch,
+}
+
+impl fmt::Display for InflateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::Truncated => "compressed data ends unexpectedly",
+            Self::InvalidHeader => "invalid zlib header",
+            Self::PresetDictionary => "zlib preset dictionaries are not supported",
+            Self::InvalidBlockType => "invalid DEFLATE block type",
+            Self::InvalidStoredLength => "stored block length does not match its complement",
+            Self::InvalidCodeLengths => "invalid Huffman code lengths",
+            Self::InvalidSymbol => "invalid Huffman symbol",
+            Self::InvalidDistance => "back-reference reaches before the start of the data",
+            Self::TooLarge => "decompressed data is larger than expected",
+            Self::ChecksumMismatch => "decompressed data does not match its checksum",
+        };
+        f.write_str(message)
+    }
+}
+
+impl Error for InflateError {}
+
+// Lengths 3 to 258 are coded as symbols 257 to 285 plus extra bits, as are
+// distances 1 to 32768 as symbols 0 to 29.
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+// The longest match, 258 bytes, takes at least two bits, so no stream
+// decompresses to more than this many times its size.
+const MAX_EXPANSION: usize = 1032;
+
+// Code lengths of the fixed literal/length code used by block type 1.
+fn fixed_literal_lengths() -> [u8; 288] {
+    let mut lengths = [8; 288];
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths
+}
@@ -0,0 +1,211 @@
+use std::{ops::Deref, ptr};
+
+use crate::{
+    EPSILON,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Vector},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Intersection<'a> {
+    pub t: f32,
+    pub object: &'a dyn Shape,
+    // Barycentric coordinates of the hit, set only by triangles.
+    pub u: f32,
+    pub v: f32,
+}
+
+impl<'a> Intersection<'a> {
+    pub const fn new(t: f32, object: &'a dyn Shape) -> Self {
+        Self::with_uv(t, object, 0., 0.)
+    }
+
+    pub const fn with_uv(t: f32, object: &'a dyn Shape, u: f32, v: f32) -> Self {
+        Self {
+            t,
+            object,
+            u,
+            v,
+        }
+    }
+
+    // `xs` is the full, sorted list this hit came from; it is needed to work
+    // out which objects the ray is inside of on either side of the hit.
+    pub fn prepare_computations(&self, ray: &Ray, xs: &[Intersection<'a>]) -> Computations<'a> {
+        let point = ray.position(self.t);
+        let eyev = -ray.direction;
+        let normalv = self.object.normal_at_hit(point, self);
+
+        // A normal pointing away from the eye means the hit is on the inside
+        // of the object, so flip it to keep shading on the visible side.
+        let inside = normalv.dot(&eyev) < 0.;
+        let normalv = if inside { -normalv } else { normalv };
+
+        let (n1, n2) = match xs.iter().position(|i| i == self) {
+            Some(idx) => (
+                innermost_refractive_index(&xs[..idx]),
+                innermost_refractive_index(&xs[..=idx]),
+            ),
+            None => (1., 1.),
+        };
+
+        Computations {
+            t: self.t,
+            object: self.object,
+            point,
+            over_point: point + normalv * EPSILON,
+            under_point: point - normalv * EPSILON,
+            eyev,
+            normalv,
+            reflectv: ray.direction.reflect(&normalv),
+            inside,
+            n1,
+            n2,
+        }
+    }
+}
+
+// Refractive index of the object most recently entered and not yet left after
+// passing through `xs`, or 1.0 for empty space. An object is inside when it
+// appears an odd number of times, and its last appearance is where it was
+// entered, so scanning backwards finds the innermost one first.
+fn innermost_refractive_index(xs: &[Intersection]) -> f32 {
+    xs.iter()
+        .enumerate()
+        .rev()
+        .find(|&(idx, i)| {
+            let same = |other: &Intersection| ptr::addr_eq(other.object, i.object);
+            !xs[idx + 1..].iter().any(same)
+                && xs[..=idx].iter().filter(|other| same(other)).count() % 2 == 1
+        })
+        .map_or(1., |(_, i)| i.object.material().refractive_index)
+}
+
+impl PartialEq for Intersection<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.t - other.t).abs() < EPSILON && ptr::addr_eq(self.object, other.object)
+    }
+}
+
+// Kept sorted by `t` on every insert. Callers own the buffer and `clear` it
+// between rays, so the backing allocation is reused across the whole render.
+#[derive(Clone, Debug, Default)]
+pub struct Intersections<'a> {
+    items: Vec<Intersection<'a>>,
+    // Buffers lent out by `scratch`, kept for the next ray once returned.
+    spare: Vec<Intersections<'a>>,
+}
+
+impl<'a> Intersections<'a> {
+    pub const fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            spare: Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+            spare: Vec::new(),
+        }
+    }
+
+    // An empty buffer for hits that must be kept apart from these, such as
+    // those on one child of a CSG shape. Handing it back with `recycle` lets
+    // later rays reuse its allocation, along with any scratch buffers it
+    // lent out in turn.
+    pub fn scratch(&mut self) -> Self {
+        self.spare.pop().unwrap_or_default()
+    }
+
+    pub fn recycle(&mut self, mut buffer: Self) {
+        buffer.clear();
+        self.spare.push(buffer);
+    }
+
+    pub fn push(&mut self, intersection: Intersection<'a>) {
+        let idx = self.items.partition_point(|i| i.t <= intersection.t);
+        self.items.insert(idx, intersection);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    // Visits the intersections in order, so `f` may track state across them.
+    pub fn retain(&mut self, f: impl FnMut(&Intersection<'a>) -> bool) {
+        self.items.retain(f);
+    }
+
+    pub fn hit(&self) -> Option<&Intersection<'a>> {
+        let idx = self.items.partition_point(|i| i.t < 0.);
+        self.items.get(idx)
+    }
+}
+
+impl<'a> Deref for Intersections<'a> {
+    type Target = [Intersection<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<'a> Extend<Intersection<'a>> for Intersections<'a> {
+    fn extend<T: IntoIterator<Item = Intersection<'a>>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|i| self.push(i));
+    }
+}
+
+impl<'a> FromIterator<Intersection<'a>> for Intersections<'a> {
+    fn from_iter<T: IntoIterator<Item = Intersection<'a>>>(iter: T) -> Self {
+        let mut xs = Self::new();
+        xs.extend(iter);
+        xs
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Computations<'a> {
+    pub t: f32,
+    pub object: &'a dyn Shape,
+    pub point: Point,
+    // Nudged off the surface along the normal so rays cast from it do not
+    // immediately hit the object they start on.
+    pub over_point: Point,
+    // Nudged below the surface, where refracted rays start.
+    pub under_point: Point,
+    pub eyev: Vector,
+    pub normalv: Vector,
+    pub reflectv: Vector,
+    pub inside: bool,
+    // Refractive indices on the side the ray arrives from and the side it
+    // continues into.
+    pub n1: f32,
+    pub n2: f32,
+}
+
+impl Computations<'_> {
+    // Schlick's approximation of the Fresnel reflectance.
+    pub fn schlick(&self) -> f32 {
+        let mut cos = self.eyev.dot(&self.normalv);
+
+        if self.n1 > self.n2 {
+            let n = self.n1 / self.n2;
+            let sin2_t = n * n * (1. - cos * cos);
+            if sin2_t > 1. {
+                return 1.;
+            }
+            cos = (1. - sin2_t).sqrt();
+        }
+
+        let r0 = ((self.n1 - self.n2) / (self.n1 + self.n2)).powi(2);
+        r0 + (1. - r0) * (1. - cos).powi(5)
+    }
+}
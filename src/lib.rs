@@ -1,6 +1,17 @@
+pub mod camera;
 pub mod canvas;
 pub mod color;
+pub mod compress;
+pub mod intersection;
+pub mod light;
+pub mod material;
 pub mod matrix;
+pub mod obj;
+pub mod pattern;
+pub mod ray;
+pub mod rng;
+pub mod shape;
 pub mod tuple;
+pub mod world;
 
 pub const EPSILON: f32 = 1e-5;
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::{
+    color::Color,
+    light::PointLight,
+    pattern::Pattern,
+    shape::Shape,
+    tuple::{Point, Vector},
+};
+
+#[derive(Clone, Debug)]
+pub struct Material {
+    pub color: Color,
+    // Takes precedence over `color` when set.
+    pub pattern: Option<Arc<dyn Pattern>>,
+    pub ambient: f32,
+    pub diffuse: f32,
+    pub specular: f32,
+    pub shininess: f32,
+    pub reflective: f32,
+    pub transparency: f32,
+    pub refractive_index: f32,
+}
+
+impl Material {
+    pub fn with_pattern(mut self, pattern: impl Pattern + 'static) -> Self {
+        self.pattern = Some(Arc::new(pattern));
+        self
+    }
+
+    pub fn color_at(&self, object: &dyn Shape, point: Point) -> Color {
+        match &self.pattern {
+            Some(pattern) => pattern.pattern_at_shape(object, point),
+            None => self.color,
+        }
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            pattern: None,
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.,
+            reflective: 0.,
+            transparency: 0.,
+            refractive_index: 1.,
+        }
+    }
+}
+
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let same_pattern = match (&self.pattern, &other.pattern) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_pattern
+            && self.color == other.color
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+    }
+}
+
+pub fn lighting(
+    material: &Material,
+    object: &dyn Shape,
+    light: &PointLight,
+    point: Point,
+    eyev: Vector,
+    normalv: Vector,
+    in_shadow: bool,
+) -> Color {
+    let effective_color = material.color_at(object, point) * light.intensity;
+    let lightv = (light.position - point).normalize();
+    let ambient = effective_color * material.ambient;
+
+    if in_shadow {
+        return ambient;
+    }
+
+    // A negative dot product means the light is on the other side of the
+    // surface, leaving only the ambient term.
+    let light_dot_normal = lightv.dot(&normalv);
+    if light_dot_normal < 0. {
+        return ambient;
+    }
+
+    let diffuse = effective_color * material.diffuse * light_dot_normal;
+
+    let reflectv = (-lightv).reflect(&normalv);
+    let reflect_dot_eye = reflectv.dot(&eyev);
+    let specular = if reflect_dot_eye <= 0. {
+        Color::BLACK
+    } else {
+        light.intensity * material.specular * reflect_dot_eye.powf(material.shininess)
+    };
+
+    ambient + diffuse + specular
+}
@@ -0,0 +1,348 @@
+use std::{
+    error::Error,
+    fmt,
+    ops::{Index, IndexMut, Mul},
+};
+
+use crate::{
+    EPSILON,
+    matrix::Matrix,
+    tuple::{Point, Tuple, Vector},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Matrix4([[f32; 4]; 4]);
+
+impl Matrix4 {
+    pub const IDENTITY: Self = Self([
+        [1., 0., 0., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., 1., 0.],
+        [0., 0., 0., 1.],
+    ]);
+
+    pub const fn new(data: [[f32; 4]; 4]) -> Self {
+        Self(data)
+    }
+
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    pub const fn transpose(&self) -> Self {
+        let m = &self.0;
+        let mut data = [[0.; 4]; 4];
+        let mut row = 0;
+        while row < 4 {
+            let mut col = 0;
+            while col < 4 {
+                data[col][row] = m[row][col];
+                col += 1;
+            }
+            row += 1;
+        }
+        Self(data)
+    }
+
+    pub const fn determinant(&self) -> f32 {
+        let (s, c) = self.sub_determinants();
+        determinant_from(&s, &c)
+    }
+
+    pub const fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Some(inverse) => inverse,
+            None => panic!("Matrix is not invertible"),
+        }
+    }
+
+    pub const fn try_inverse(&self) -> Option<Self> {
+        let m = &self.0;
+        let (s, c) = self.sub_determinants();
+        let det = determinant_from(&s, &c);
+
+        // Same scale-aware bound as `Matrix`: the product of the row norms.
+        // Squaring instead would overflow or underflow at extreme scales. An
+        // affine transform has the determinant of its upper-left 3x3 block,
+        // so its translation column is left out of the bound.
+        let size = if is_affine(m) { 3 } else { 4 };
+        let mut bound = 1.;
+        let mut row = 0;
+        while row < size {
+            let mut sum = 0.;
+            let mut col = 0;
+            while col < size {
+                sum += m[row][col] * m[row][col];
+                col += 1;
+            }
+            bound *= const_sqrt(sum);
+            row += 1;
+        }
+        if !det.is_finite() || det.abs() <= EPSILON * bound {
+            return None;
+        }
+
+        let inv = 1. / det;
+        let [m00, m01, m02, m03] = m[0];
+        let [m10, m11, m12, m13] = m[1];
+        let [m20, m21, m22, m23] = m[2];
+        let [m30, m31, m32, m33] = m[3];
+
+        Some(Self([
+            [
+                (m11 * c[5] - m12 * c[4] + m13 * c[3]) * inv,
+                (-m01 * c[5] + m02 * c[4] - m03 * c[3]) * inv,
+                (m31 * s[5] - m32 * s[4] + m33 * s[3]) * inv,
+                (-m21 * s[5] + m22 * s[4] - m23 * s[3]) * inv,
+            ],
+            [
+                (-m10 * c[5] + m12 * c[2] - m13 * c[1]) * inv,
+                (m00 * c[5] - m02 * c[2] + m03 * c[1]) * inv,
+                (-m30 * s[5] + m32 * s[2] - m33 * s[1]) * inv,
+                (m20 * s[5] - m22 * s[2] + m23 * s[1]) * inv,
+            ],
+            [
+                (m10 * c[4] - m11 * c[2] + m13 * c[0]) * inv,
+                (-m00 * c[4] + m01 * c[2] - m03 * c[0]) * inv,
+                (m30 * s[4] - m31 * s[2] + m33 * s[0]) * inv,
+                (-m20 * s[4] + m21 * s[2] - m23 * s[0]) * inv,
+            ],
+            [
+                (-m10 * c[3] + m11 * c[1] - m12 * c[0]) * inv,
+                (m00 * c[3] - m01 * c[1] + m02 * c[0]) * inv,
+                (-m30 * s[3] + m31 * s[1] - m32 * s[0]) * inv,
+                (m20 * s[3] - m21 * s[1] + m22 * s[0]) * inv,
+            ],
+        ]))
+    }
+
+    // 2x2 determinants of the top two rows (`s`) and the bottom two rows
+    // (`c`), shared by the determinant and the inverse.
+    const fn sub_determinants(&self) -> ([f32; 6], [f32; 6]) {
+        let [m00, m01, m02, m03] = self.0[0];
+        let [m10, m11, m12, m13] = self.0[1];
+        let [m20, m21, m22, m23] = self.0[2];
+        let [m30, m31, m32, m33] = self.0[3];
+
+        (
+            [
+                m00 * m11 - m10 * m01,
+                m00 * m12 - m10 * m02,
+                m00 * m13 - m10 * m03,
+                m01 * m12 - m11 * m02,
+                m01 * m13 - m11 * m03,
+                m02 * m13 - m12 * m03,
+            ],
+            [
+                m20 * m31 - m30 * m21,
+                m20 * m32 - m30 * m22,
+                m20 * m33 - m30 * m23,
+                m21 * m32 - m31 * m22,
+                m21 * m33 - m31 * m23,
+                m22 * m33 - m32 * m23,
+            ],
+        )
+    }
+}
+
+const fn is_affine(m: &[[f32; 4]; 4]) -> bool {
+    m[3][0] == 0. && m[3][1] == 0. && m[3][2] == 0. && m[3][3] == 1.
+}
+
+// Laplace expansion over the 2x2 minors of the top and bottom row pairs.
+const fn determinant_from(s: &[f32; 6], c: &[f32; 6]) -> f32 {
+    s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+}
+
+// `f32::sqrt` is not const. A first guess from halving the exponent bits and
+// a few Newton steps are plenty for a tolerance.
+const fn const_sqrt(x: f32) -> f32 {
+    if x.is_nan() || x <= 0. || x.is_infinite() {
+        return x;
+    }
+
+    let mut y = f32::from_bits((x.to_bits() >> 1) + 0x1FC0_0000);
+    let mut step = 0;
+    while step < 4 {
+        y = 0.5 * (y + x / y);
+        step += 1;
+    }
+    y
+}
+
+impl Default for Matrix4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Index<usize> for Matrix4 {
+    type Output = [f32; 4];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<usize> for Matrix4 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl PartialEq for Matrix4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .flatten()
+            .zip(other.0.iter().flatten())
+            .all(|(a, b)| (a - b).abs() < EPSILON)
+    }
+}
+
+impl Mul for Matrix4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut data = [[0.; 4]; 4];
+        (0..4).for_each(|row| {
+            (0..4).for_each(|col| {
+                data[row][col] = self[row][0] * rhs[0][col]
+                    + self[row][1] * rhs[1][col]
+                    + self[row][2] * rhs[2][col]
+                    + self[row][3] * rhs[3][col];
+            });
+        });
+        Self(data)
+    }
+}
+
+impl Mul for &Matrix4 {
+    type Output = Matrix4;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl Mul<&Matrix4> for Matrix4 {
+    type Output = Self;
+
+    fn mul(self, rhs: &Matrix4) -> Self::Output {
+        self * *rhs
+    }
+}
+
+impl Mul<Matrix4> for &Matrix4 {
+    type Output = Matrix4;
+
+    fn mul(self, rhs: Matrix4) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl Mul<Tuple> for Matrix4 {
+    type Output = Tuple;
+
+    fn mul(self, rhs: Tuple) -> Self::Output {
+        let row = |r: [f32; 4]| r[0] * rhs.x + r[1] * rhs.y + r[2] * rhs.z + r[3] * rhs.w;
+        Tuple::new(row(self[0]), row(self[1]), row(self[2]), row(self[3]))
+    }
+}
+
+impl Mul<Tuple> for &Matrix4 {
+    type Output = Tuple;
+
+    fn mul(self, rhs: Tuple) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl Mul<Point> for Matrix4 {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        Point(self * rhs.0)
+    }
+}
+
+impl Mul<Point> for &Matrix4 {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl Mul<Vector> for Matrix4 {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        Vector(self * rhs.0)
+    }
+}
+
+impl Mul<Vector> for &Matrix4 {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl From<[[f32; 4]; 4]> for Matrix4 {
+    fn from(data: [[f32; 4]; 4]) -> Self {
+        Self(data)
+    }
+}
+
+impl From<Matrix4> for Matrix {
+    fn from(m: Matrix4) -> Self {
+        Self::from_iter(m.0)
+    }
+}
+
+impl From<&Matrix4> for Matrix {
+    fn from(m: &Matrix4) -> Self {
+        Self::from(*m)
+    }
+}
+
+impl TryFrom<&Matrix> for Matrix4 {
+    type Error = NotFourByFour;
+
+    fn try_from(m: &Matrix) -> Result<Self, Self::Error> {
+        if m.rows() != 4 || m.cols() != 4 {
+            return Err(NotFourByFour {
+                rows: m.rows(),
+                cols: m.cols(),
+            });
+        }
+
+        let mut data = [[0.; 4]; 4];
+        (0..4).for_each(|row| data[row].copy_from_slice(&m[row]));
+        Ok(Self(data))
+    }
+}
+
+impl TryFrom<Matrix> for Matrix4 {
+    type Error = NotFourByFour;
+
+    fn try_from(m: Matrix) -> Result<Self, Self::Error> {
+        Self::try_from(&m)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotFourByFour {
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl fmt::Display for NotFourByFour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a 4x4 matrix, got {}x{}", self.rows, self.cols)
+    }
+}
+
+impl Error for NotFourByFour {}
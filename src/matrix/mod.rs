@@ -1,6 +1,14 @@
+mod matrix4;
+mod transform;
+
 use std::ops::{Index, IndexMut, Mul};
 
-use crate::tuple::Tuple;
+use crate::{
+    EPSILON,
+    tuple::{Point, Tuple, Vector},
+};
+
+pub use matrix4::{Matrix4, NotFourByFour};
 
 #[derive(Debug, Clone)]
 pub struct Matrix {
@@ -110,6 +118,101 @@ impl Matrix {
         let sign = if (row + col) & 1 == 0 { 1. } else { -1. };
         sign * self.minor(row, col)
     }
+
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().expect("Matrix is not invertible")
+    }
+
+    pub fn try_inverse(&self) -> Option<Self> {
+        assert!(
+            self.rows == self.cols,
+            "Inverse is only defined for square matrices"
+        );
+
+        match self.rows {
+            4 => self.inverse_4x4(),
+            _ => self.inverse_gauss_jordan(),
+        }
+    }
+
+    // Hadamard's inequality bounds |det| by the product of the row norms, so
+    // comparing against it keeps the singularity test independent of scale.
+    // When the last row is all zeros but a final 1, as in an affine
+    // transform, |det| is that of the block without the last row and column,
+    // so the translation column is left out of the bound.
+    fn is_singular(&self, det: f32) -> bool {
+        let n = self.cols;
+        let affine = self.data.chunks(n).last().is_some_and(|row| {
+            let (last, rest) = row.split_last().expect("rows are not empty");
+            *last == 1. && rest.iter().all(|&v| v == 0.)
+        });
+        let size = if affine { n - 1 } else { n };
+
+        let bound: f32 = self
+            .data
+            .chunks(n)
+            .take(size)
+            .map(|row| row[..size].iter().map(|v| v * v).sum::<f32>().sqrt())
+            .product();
+
+        !det.is_finite() || det.abs() <= EPSILON * bound
+    }
+
+    fn inverse_4x4(&self) -> Option<Self> {
+        Matrix4::try_from(self).ok()?.try_inverse().map(Self::from)
+    }
+
+    fn inverse_gauss_jordan(&self) -> Option<Self> {
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut inv = Self::identity(n, n).data;
+        let mut det = 1.;
+
+        for col in 0..n {
+            let pivot =
+                (col..n).max_by(|&i, &j| a[i * n + col].abs().total_cmp(&a[j * n + col].abs()))?;
+
+            if pivot != col {
+                for c in 0..n {
+                    a.swap(pivot * n + c, col * n + c);
+                    inv.swap(pivot * n + c, col * n + c);
+                }
+                det = -det;
+            }
+
+            let p = a[col * n + col];
+            det *= p;
+            if p == 0. {
+                return None;
+            }
+
+            for c in 0..n {
+                a[col * n + c] /= p;
+                inv[col * n + c] /= p;
+            }
+
+            for row in (0..n).filter(|&row| row != col) {
+                let factor = a[row * n + col];
+                if factor == 0. {
+                    continue;
+                }
+                for c in 0..n {
+                    a[row * n + c] -= factor * a[col * n + c];
+                    inv[row * n + c] -= factor * inv[col * n + c];
+                }
+            }
+        }
+
+        if self.is_singular(det) {
+            return None;
+        }
+
+        Some(Self {
+            data: inv,
+            rows: n,
+            cols: n,
+        })
+    }
 }
 
 impl Index<usize> for Matrix {
@@ -132,7 +235,13 @@ impl IndexMut<usize> for Matrix {
 
 impl PartialEq for Matrix {
     fn eq(&self, other: &Self) -> bool {
-        self.data == other.data
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| (a - b).abs() < EPSILON)
     }
 }
 
@@ -215,3 +324,19 @@ impl Mul<Tuple> for Matrix {
         Tuple::new(result[0], result[1], result[2], result[3])
     }
 }
+
+impl Mul<Point> for Matrix {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        Point(self * rhs.0)
+    }
+}
+
+impl Mul<Vector> for Matrix {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        Vector(self * rhs.0)
+    }
+}
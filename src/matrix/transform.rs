@@ -0,0 +1,198 @@
+use crate::{
+    matrix::{Matrix, Matrix4},
+    tuple::{Point, Vector},
+};
+
+impl Matrix4 {
+    pub const fn translation(x: f32, y: f32, z: f32) -> Self {
+        Self::new([
+            [1., 0., 0., x],
+            [0., 1., 0., y],
+            [0., 0., 1., z],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub const fn scaling(x: f32, y: f32, z: f32) -> Self {
+        Self::new([
+            [x, 0., 0., 0.],
+            [0., y, 0., 0.],
+            [0., 0., z, 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub fn rotation_x(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new([
+            [1., 0., 0., 0.],
+            [0., cos, -sin, 0.],
+            [0., sin, cos, 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub fn rotation_y(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new([
+            [cos, 0., sin, 0.],
+            [0., 1., 0., 0.],
+            [-sin, 0., cos, 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub fn rotation_z(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new([
+            [cos, -sin, 0., 0.],
+            [sin, cos, 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    // Rodrigues' rotation formula; the axis does not need to be normalized.
+    pub fn rotation(axis: Vector, radians: f32) -> Self {
+        let axis = axis.normalize();
+        let (x, y, z) = (axis.x, axis.y, axis.z);
+        let (sin, cos) = radians.sin_cos();
+        let t = 1. - cos;
+
+        Self::new([
+            [
+                t * x * x + cos,
+                t * x * y - sin * z,
+                t * x * z + sin * y,
+                0.,
+            ],
+            [
+                t * x * y + sin * z,
+                t * y * y + cos,
+                t * y * z - sin * x,
+                0.,
+            ],
+            [
+                t * x * z - sin * y,
+                t * y * z + sin * x,
+                t * z * z + cos,
+                0.,
+            ],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub const fn shearing(xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Self {
+        Self::new([
+            [1., xy, xz, 0.],
+            [yx, 1., yz, 0.],
+            [zx, zy, 1., 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    // Orients the world relative to an eye at `from` looking towards `to`;
+    // `up` only needs to point roughly upwards.
+    pub fn view_transform(from: Point, to: Point, up: Vector) -> Self {
+        let forward = (to - from).normalize();
+        let left = forward.cross(&up.normalize());
+        let true_up = left.cross(&forward);
+
+        let orientation = Self::new([
+            [left.x, left.y, left.z, 0.],
+            [true_up.x, true_up.y, true_up.z, 0.],
+            [-forward.x, -forward.y, -forward.z, 0.],
+            [0., 0., 0., 1.],
+        ]);
+
+        orientation * Self::translation(-from.x, -from.y, -from.z)
+    }
+
+    // The chaining methods apply their transform after `self`, so a chain
+    // reads in the order the steps are applied to a point.
+    pub fn translate(self, x: f32, y: f32, z: f32) -> Self {
+        Self::translation(x, y, z) * self
+    }
+
+    pub fn scale(self, x: f32, y: f32, z: f32) -> Self {
+        Self::scaling(x, y, z) * self
+    }
+
+    pub fn rotate_x(self, radians: f32) -> Self {
+        Self::rotation_x(radians) * self
+    }
+
+    pub fn rotate_y(self, radians: f32) -> Self {
+        Self::rotation_y(radians) * self
+    }
+
+    pub fn rotate_z(self, radians: f32) -> Self {
+        Self::rotation_z(radians) * self
+    }
+
+    pub fn rotate(self, axis: Vector, radians: f32) -> Self {
+        Self::rotation(axis, radians) * self
+    }
+
+    pub fn shear(self, xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Self {
+        Self::shearing(xy, xz, yx, yz, zx, zy) * self
+    }
+}
+
+impl Matrix {
+    pub fn translation(x: f32, y: f32, z: f32) -> Self {
+        Matrix4::translation(x, y, z).into()
+    }
+
+    pub fn scaling(x: f32, y: f32, z: f32) -> Self {
+        Matrix4::scaling(x, y, z).into()
+    }
+
+    pub fn rotation_x(radians: f32) -> Self {
+        Matrix4::rotation_x(radians).into()
+    }
+
+    pub fn rotation_y(radians: f32) -> Self {
+        Matrix4::rotation_y(radians).into()
+    }
+
+    pub fn rotation_z(radians: f32) -> Self {
+        Matrix4::rotation_z(radians).into()
+    }
+
+    pub fn rotation(axis: Vector, radians: f32) -> Self {
+        Matrix4::rotation(axis, radians).into()
+    }
+
+    pub fn shearing(xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Self {
+        Matrix4::shearing(xy, xz, yx, yz, zx, zy).into()
+    }
+
+    pub fn translate(self, x: f32, y: f32, z: f32) -> Self {
+        Self::translation(x, y, z) * self
+    }
+
+    pub fn scale(self, x: f32, y: f32, z: f32) -> Self {
+        Self::scaling(x, y, z) * self
+    }
+
+    pub fn rotate_x(self, radians: f32) -> Self {
+        Self::rotation_x(radians) * self
+    }
+
+    pub fn rotate_y(self, radians: f32) -> Self {
+        Self::rotation_y(radians) * self
+    }
+
+    pub fn rotate_z(self, radians: f32) -> Self {
+        Self::rotation_z(radians) * self
+    }
+
+    pub fn rotate(self, axis: Vector, radians: f32) -> Self {
+        Self::rotation(axis, radians) * self
+    }
+
+    pub fn shear(self, xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Self {
+        Self::shearing(xy, xz, yx, yz, zx, zy) * self
+    }
+}
@@ -0,0 +1,246 @@
+use std::{error::Error, fmt, ops::RangeInclusive};
+
+use crate::{
+    shape::{Group, SmoothTriangle, Triangle},
+    tuple::{Point, Vector},
+};
+
+// A parsed Wavefront OBJ file. Faces before the first `g` statement go into
+// the default group; each named group collects every face listed under that
+// name, wherever it appears in the file. Vertex lists keep file order, so the
+// OBJ index `n` is element `n - 1`.
+#[derive(Debug, Default)]
+pub struct ObjFile {
+    pub vertices: Vec<Point>,
+    pub normals: Vec<Vector>,
+    pub texture_vertices: Vec<Point>,
+    // Statements this parser does not understand, such as `usemtl` or `s`.
+    pub ignored: usize,
+    default_group: Group,
+    groups: Vec<(String, Group)>,
+}
+
+impl ObjFile {
+    pub fn parse(input: &str) -> Result<Self, ParseObjError> {
+        let mut obj = Self::default();
+        // Index into `groups` of the group faces are added to, if any.
+        let mut current: Option<usize> = None;
+
+        for (idx, line) in input.lines().enumerate() {
+            let line_number = idx + 1;
+            let err = |kind| ParseObjError {
+                line: line_number,
+                kind,
+            };
+
+            let line = line.split('#').next().unwrap_or_default();
+            let mut tokens = line.split_whitespace();
+            let Some(keyword) = tokens.next() else {
+                continue;
+            };
+            let args: Vec<&str> = tokens.collect();
+
+            match keyword {
+                "v" => {
+                    let [x, y, z] = parse_floats(&args, 3, 4).map_err(err)?;
+                    obj.vertices.push(Point::new(x, y, z));
+                }
+                "vn" => {
+                    let [x, y, z] = parse_floats(&args, 3, 3).map_err(err)?;
+                    obj.normals.push(Vector::new(x, y, z));
+                }
+                "vt" => {
+                    let [u, v, w] = parse_floats(&args, 1, 3).map_err(err)?;
+                    obj.texture_vertices.push(Point::new(u, v, w));
+                }
+                "f" => {
+                    if args.len() < 3 {
+                        return Err(err(ObjErrorKind::TooFewFaceVertices(args.len())));
+                    }
+                    let corners = args
+                        .iter()
+                        .map(|arg| obj.parse_face_vertex(arg))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(err)?;
+
+                    let group = match current {
+                        Some(idx) => &mut obj.groups[idx].1,
+                        None => &mut obj.default_group,
+                    };
+                    fan_triangulate(group, &corners);
+                }
+                "g" => {
+                    let name = args.join(" ");
+                    current = Some(
+                        match obj.groups.iter().position(|(other, _)| *other == name) {
+                            Some(idx) => idx,
+                            None => {
+                                obj.groups.push((name, Group::new()));
+                                obj.groups.len() - 1
+                            }
+                        },
+                    );
+                }
+                _ => obj.ignored += 1,
+            }
+        }
+
+        Ok(obj)
+    }
+
+    pub const fn default_group(&self) -> &Group {
+        &self.default_group
+    }
+
+    pub fn group(&self, name: &str) -> Option<&Group> {
+        self.groups
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, group)| group)
+    }
+
+    pub fn group_names(&self) -> impl Iterator<Item = &str> {
+        self.groups.iter().map(|(name, _)| name.as_str())
+    }
+
+    // Everything in the file as one shape: the default group's faces plus
+    // one child group per named group.
+    pub fn into_group(self) -> Group {
+        let mut group = self.default_group;
+        self.groups
+            .into_iter()
+            .for_each(|(_, child)| group.add_child(child));
+        group
+    }
+
+    // One `v`, `v/vt`, `v//vn` or `v/vt/vn` reference from a face.
+    fn parse_face_vertex(&self, arg: &str) -> Result<(Point, Option<Vector>), ObjErrorKind> {
+        let parts: Vec<&str> = arg.split('/').collect();
+        let (vertex, texture, normal) = match parts[..] {
+            [vertex] => (vertex, None, None),
+            [vertex, texture] => (vertex, Some(texture), None),
+            [vertex, "", normal] => (vertex, None, Some(normal)),
+            [vertex, texture, normal] => (vertex, Some(texture), Some(normal)),
+            _ => return Err(ObjErrorKind::InvalidFaceVertex(arg.to_string())),
+        };
+
+        let vertex = self.vertices[resolve_index(vertex, self.vertices.len())?];
+        if let Some(texture) = texture {
+            resolve_index(texture, self.texture_vertices.len())?;
+        }
+        let normal = normal
+            .map(|normal| resolve_index(normal, self.normals.len()).map(|idx| self.normals[idx]))
+            .transpose()?;
+
+        Ok((vertex, normal))
+    }
+}
+
+// `N` values, of which at least `min` and at most `max` must be given; the
+// rest are zero. Every argument must be a number, including any given past
+// the first `N`, such as the optional weight of a vertex.
+fn parse_floats<const N: usize>(
+    args: &[&str],
+    min: usize,
+    max: usize,
+) -> Result<[f32; N], ObjErrorKind> {
+    if args.len() < min || args.len() > max {
+        return Err(ObjErrorKind::WrongArgumentCount {
+            expected: min..=max,
+            found: args.len(),
+        });
+    }
+
+    let mut values = [0.; N];
+    for (idx, arg) in args.iter().enumerate() {
+        let value = arg
+            .parse()
+            .map_err(|_| ObjErrorKind::InvalidNumber(arg.to_string()))?;
+        if let Some(slot) = values.get_mut(idx) {
+            *slot = value;
+        }
+    }
+    Ok(values)
+}
+
+// OBJ indices start at 1, and negative ones count back from the most recent
+// element, so -1 is the last one defined so far.
+fn resolve_index(index: &str, len: usize) -> Result<usize, ObjErrorKind> {
+    let parsed: i64 = index
+        .parse()
+        .map_err(|_| ObjErrorKind::InvalidNumber(index.to_string()))?;
+
+    let resolved = match parsed {
+        1.. => parsed - 1,
+        ..0 => len as i64 + parsed,
+        0 => -1,
+    };
+    usize::try_from(resolved)
+        .ok()
+        .filter(|&idx| idx < len)
+        .ok_or(ObjErrorKind::IndexOutOfRange(parsed))
+}
+
+// Splits a convex polygon into triangles that all share its first vertex.
+// Faces are smooth only when every corner has a normal.
+fn fan_triangulate(group: &mut Group, corners: &[(Point, Option<Vector>)]) {
+    let (p1, n1) = corners[0];
+    for pair in corners[1..].windows(2) {
+        let [(p2, n2), (p3, n3)] = [pair[0], pair[1]];
+        match (n1, n2, n3) {
+            (Some(n1), Some(n2), Some(n3)) => {
+                group.add_child(SmoothTriangle::new(p1, p2, p3, n1, n2, n3))
+            }
+            _ => group.add_child(Triangle::new(p1, p2, p3)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseObjError {
+    pub line: usize,
+    pub kind: ObjErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjErrorKind {
+    InvalidNumber(String),
+    WrongArgumentCount {
+        expected: RangeInclusive<usize>,
+        found: usize,
+    },
+    TooFewFaceVertices(usize),
+    InvalidFaceVertex(String),
+    IndexOutOfRange(i64),
+}
+
+impl fmt::Display for ParseObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ObjErrorKind::InvalidNumber(s) => write!(f, "invalid number {s:?}"),
+            ObjErrorKind::WrongArgumentCount {
+                expected,
+                found,
+            } if expected.start() == expected.end() => {
+                write!(f, "expected {} values, found {found}", expected.start())
+            }
+            ObjErrorKind::WrongArgumentCount {
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} to {} values, found {found}",
+                expected.start(),
+                expected.end()
+            ),
+            ObjErrorKind::TooFewFaceVertices(n) => {
+                write!(f, "a face needs at least 3 vertices, found {n}")
+            }
+            ObjErrorKind::InvalidFaceVertex(s) => write!(f, "invalid face vertex {s:?}"),
+            ObjErrorKind::IndexOutOfRange(idx) => write!(f, "index {idx} is out of range"),
+        }
+    }
+}
+
+impl Error for ParseObjError {}
@@ -0,0 +1,46 @@
+use crate::{
+    color::Color,
+    pattern::{Pattern, PatternData},
+    tuple::Point,
+};
+
+// Mixes two patterns, weighting `b` by `factor`.
+#[derive(Debug)]
+pub struct Blend {
+    data: PatternData,
+    pub a: Box<dyn Pattern>,
+    pub b: Box<dyn Pattern>,
+    pub factor: f32,
+}
+
+impl Blend {
+    pub fn new(a: impl Into<Box<dyn Pattern>>, b: impl Into<Box<dyn Pattern>>) -> Self {
+        Self {
+            data: PatternData::default(),
+            a: a.into(),
+            b: b.into(),
+            factor: 0.5,
+        }
+    }
+
+    pub const fn with_factor(mut self, factor: f32) -> Self {
+        self.factor = factor;
+        self
+    }
+}
+
+impl Pattern for Blend {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let a = self.a.pattern_at(point);
+        let b = self.b.pattern_at(point);
+        a * (1. - self.factor) + b * self.factor
+    }
+}
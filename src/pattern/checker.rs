@@ -0,0 +1,42 @@
+use crate::{
+    color::Color,
+    pattern::{Pattern, PatternData},
+    tuple::Point,
+};
+
+// Alternating unit cubes in all three dimensions.
+#[derive(Debug)]
+pub struct Checker {
+    data: PatternData,
+    pub a: Box<dyn Pattern>,
+    pub b: Box<dyn Pattern>,
+}
+
+impl Checker {
+    pub fn new(a: impl Into<Box<dyn Pattern>>, b: impl Into<Box<dyn Pattern>>) -> Self {
+        Self {
+            data: PatternData::default(),
+            a: a.into(),
+            b: b.into(),
+        }
+    }
+}
+
+impl Pattern for Checker {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let sum = point.x.floor() + point.y.floor() + point.z.floor();
+        if sum.rem_euclid(2.) == 0. {
+            self.a.pattern_at(point)
+        } else {
+            self.b.pattern_at(point)
+        }
+    }
+}
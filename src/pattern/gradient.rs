@@ -0,0 +1,39 @@
+use crate::{
+    color::Color,
+    pattern::{Pattern, PatternData},
+    tuple::Point,
+};
+
+// Blends linearly from `a` to `b` across each unit along X.
+#[derive(Debug)]
+pub struct Gradient {
+    data: PatternData,
+    pub a: Box<dyn Pattern>,
+    pub b: Box<dyn Pattern>,
+}
+
+impl Gradient {
+    pub fn new(a: impl Into<Box<dyn Pattern>>, b: impl Into<Box<dyn Pattern>>) -> Self {
+        Self {
+            data: PatternData::default(),
+            a: a.into(),
+            b: b.into(),
+        }
+    }
+}
+
+impl Pattern for Gradient {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let a = self.a.pattern_at(point);
+        let b = self.b.pattern_at(point);
+        a + (b - a) * (point.x - point.x.floor())
+    }
+}
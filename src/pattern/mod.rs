@@ -0,0 +1,89 @@
+pub mod blend;
+pub mod checker;
+pub mod gradient;
+pub mod radial_gradient;
+pub mod ring;
+pub mod solid;
+pub mod stripe;
+
+use std::fmt;
+
+pub use blend::Blend;
+pub use checker::Checker;
+pub use gradient::Gradient;
+pub use radial_gradient::RadialGradient;
+pub use ring::Ring;
+pub use solid::Solid;
+pub use stripe::Stripe;
+
+use crate::{color::Color, matrix::Matrix4, shape::Shape, tuple::Point};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PatternData {
+    transform: Matrix4,
+    inverse: Matrix4,
+}
+
+impl PatternData {
+    pub const fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub const fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.inverse = transform.inverse();
+    }
+}
+
+pub trait Pattern: fmt::Debug + Send + Sync {
+    fn data(&self) -> &PatternData;
+
+    fn data_mut(&mut self) -> &mut PatternData;
+
+    // `point` is already in pattern space.
+    fn local_pattern_at(&self, point: Point) -> Color;
+
+    fn transform(&self) -> &Matrix4 {
+        self.data().transform()
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.data_mut().set_transform(transform);
+    }
+
+    fn with_transform(mut self, transform: Matrix4) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_transform(transform);
+        self
+    }
+
+    // Samples the pattern at a point in the space of whatever owns it: an
+    // object for top-level patterns, or the parent pattern when nested.
+    fn pattern_at(&self, point: Point) -> Color {
+        self.local_pattern_at(self.data().inverse() * point)
+    }
+
+    fn pattern_at_shape(&self, object: &dyn Shape, world_point: Point) -> Color {
+        self.pattern_at(object.world_to_object(world_point))
+    }
+}
+
+// Lets any slot of a composite pattern take either a flat color or another
+// pattern.
+impl From<Color> for Box<dyn Pattern> {
+    fn from(color: Color) -> Self {
+        Box::new(Solid::new(color))
+    }
+}
+
+impl<P: Pattern + 'static> From<P> for Box<dyn Pattern> {
+    fn from(pattern: P) -> Self {
+        Box::new(pattern)
+    }
+}
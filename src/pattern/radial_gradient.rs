@@ -0,0 +1,40 @@
+use crate::{
+    color::Color,
+    pattern::{Pattern, PatternData},
+    tuple::Point,
+};
+
+// Blends from `a` to `b` across each unit of distance from the Y axis.
+#[derive(Debug)]
+pub struct RadialGradient {
+    data: PatternData,
+    pub a: Box<dyn Pattern>,
+    pub b: Box<dyn Pattern>,
+}
+
+impl RadialGradient {
+    pub fn new(a: impl Into<Box<dyn Pattern>>, b: impl Into<Box<dyn Pattern>>) -> Self {
+        Self {
+            data: PatternData::default(),
+            a: a.into(),
+            b: b.into(),
+        }
+    }
+}
+
+impl Pattern for RadialGradient {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let distance = point.x.hypot(point.z);
+        let a = self.a.pattern_at(point);
+        let b = self.b.pattern_at(point);
+        a + (b - a) * (distance - distance.floor())
+    }
+}
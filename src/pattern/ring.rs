@@ -0,0 +1,41 @@
+use crate::{
+    color::Color,
+    pattern::{Pattern, PatternData},
+    tuple::Point,
+};
+
+// Concentric rings around the Y axis, alternating every unit of distance.
+#[derive(Debug)]
+pub struct Ring {
+    data: PatternData,
+    pub a: Box<dyn Pattern>,
+    pub b: Box<dyn Pattern>,
+}
+
+impl Ring {
+    pub fn new(a: impl Into<Box<dyn Pattern>>, b: impl Into<Box<dyn Pattern>>) -> Self {
+        Self {
+            data: PatternData::default(),
+            a: a.into(),
+            b: b.into(),
+        }
+    }
+}
+
+impl Pattern for Ring {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        if point.x.hypot(point.z).floor().rem_euclid(2.) == 0. {
+            self.a.pattern_at(point)
+        } else {
+            self.b.pattern_at(point)
+        }
+    }
+}
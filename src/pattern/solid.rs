@@ -0,0 +1,34 @@
+use crate::{
+    color::Color,
+    pattern::{Pattern, PatternData},
+    tuple::Point,
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Solid {
+    data: PatternData,
+    pub color: Color,
+}
+
+impl Solid {
+    pub fn new(color: Color) -> Self {
+        Self {
+            data: PatternData::default(),
+            color,
+        }
+    }
+}
+
+impl Pattern for Solid {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, _point: Point) -> Color {
+        self.color
+    }
+}
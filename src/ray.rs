@@ -0,0 +1,27 @@
+use crate::{
+    matrix::Matrix4,
+    tuple::{Point, Vector},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub const fn new(origin: Point, direction: Vector) -> Self {
+        Self {
+            origin,
+            direction,
+        }
+    }
+
+    pub fn position(&self, t: f32) -> Point {
+        self.origin + self.direction * t
+    }
+
+    pub fn transform(&self, m: &Matrix4) -> Self {
+        Self::new(m * self.origin, m * self.direction)
+    }
+}
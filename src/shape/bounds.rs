@@ -0,0 +1,188 @@
+use crate::{
+    EPSILON,
+    matrix::Matrix4,
+    ray::Ray,
+    tuple::{Point, Tuple},
+};
+
+// An axis-aligned bounding box. Unbounded shapes such as planes use infinite
+// extents, and the empty box has `min` above `max` so that adding anything to
+// it yields exactly that thing.
+#[derive(Clone, Copy, Debug)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub const EMPTY: Self = Self {
+        min: Point(Tuple::new(f32::INFINITY, f32::INFINITY, f32::INFINITY, 1.)),
+        max: Point(Tuple::new(
+            f32::NEG_INFINITY,
+            f32::NEG_INFINITY,
+            f32::NEG_INFINITY,
+            1.,
+        )),
+    };
+
+    pub const fn new(min: Point, max: Point) -> Self {
+        Self {
+            min,
+            max,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn add_point(&mut self, point: Point) {
+        self.min = Point::new(
+            self.min.x.min(point.x),
+            self.min.y.min(point.y),
+            self.min.z.min(point.z),
+        );
+        self.max = Point::new(
+            self.max.x.max(point.x),
+            self.max.y.max(point.y),
+            self.max.z.max(point.z),
+        );
+    }
+
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut merged = *self;
+        merged.add_point(other.min);
+        merged.add_point(other.max);
+        merged
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        (0..3).all(|axis| {
+            let p = axis_of(&point, axis);
+            axis_of(&self.min, axis) <= p && p <= axis_of(&self.max, axis)
+        })
+    }
+
+    pub fn contains_bounds(&self, other: &Self) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    pub fn centroid(&self) -> Point {
+        Point::new(
+            (self.min.x + self.max.x) / 2.,
+            (self.min.y + self.max.y) / 2.,
+            (self.min.z + self.max.z) / 2.,
+        )
+    }
+
+    pub fn surface_area(&self) -> f32 {
+        if self.is_empty() {
+            return 0.;
+        }
+        let (dx, dy, dz) = (
+            self.max.x - self.min.x,
+            self.max.y - self.min.y,
+            self.max.z - self.min.z,
+        );
+        2. * (dx * dy + dy * dz + dz * dx)
+    }
+
+    // The axis (0 for x, 1 for y, 2 for z) along which the box is longest.
+    pub fn longest_axis(&self) -> usize {
+        let extent = |axis| axis_of(&self.max, axis) - axis_of(&self.min, axis);
+        (1..3).fold(0, |longest, axis| {
+            if extent(axis) > extent(longest) {
+                axis
+            } else {
+                longest
+            }
+        })
+    }
+
+    // The smallest box around this one after transforming it. Each output
+    // axis takes the extreme of every input axis scaled by the matrix
+    // (Arvo's method), which avoids transforming all eight corners and keeps
+    // infinite extents from turning into NaN through `0 * inf`.
+    pub fn transform(&self, m: &Matrix4) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+
+        let mut min = [m[0][3], m[1][3], m[2][3]];
+        let mut max = min;
+        for (row, (min, max)) in min.iter_mut().zip(&mut max).enumerate() {
+            for col in 0..3 {
+                let scale = m[row][col];
+                if scale == 0. {
+                    continue;
+                }
+                let a = scale * axis_of(&self.min, col);
+                let b = scale * axis_of(&self.max, col);
+                *min += a.min(b);
+                *max += a.max(b);
+            }
+        }
+
+        Self::new(
+            Point::new(min[0], min[1], min[2]),
+            Point::new(max[0], max[1], max[2]),
+        )
+    }
+
+    // Slab test, like `Cube` but against arbitrary extents.
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let mut tmin = f32::NEG_INFINITY;
+        let mut tmax = f32::INFINITY;
+
+        for axis in 0..3 {
+            let origin = axis_of(&ray.origin, axis);
+            let inv_direction = 1. / axis_of(&ray.direction, axis);
+            let t1 = (axis_of(&self.min, axis) - origin) * inv_direction;
+            let t2 = (axis_of(&self.max, axis) - origin) * inv_direction;
+            // `max` and `min` skip the NaN from `0 * inf` when the ray runs
+            // along a face of the box.
+            tmin = tmin.max(t1.min(t2));
+            tmax = tmax.min(t1.max(t2));
+        }
+
+        tmin <= tmax && tmax >= 0.
+    }
+
+    pub(crate) fn axis_min(&self, axis: usize) -> f32 {
+        axis_of(&self.min, axis)
+    }
+
+    pub(crate) fn axis_max(&self, axis: usize) -> f32 {
+        axis_of(&self.max, axis)
+    }
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+// Infinite extents compare equal to themselves, which the EPSILON comparison
+// on `Point` alone would reject.
+impl PartialEq for Bounds {
+    fn eq(&self, other: &Self) -> bool {
+        let close = |a: f32, b: f32| a == b || (a - b).abs() < EPSILON;
+        (0..3).all(|axis| {
+            close(self.axis_min(axis), other.axis_min(axis))
+                && close(self.axis_max(axis), other.axis_max(axis))
+        })
+    }
+}
+
+pub(crate) fn axis_of(tuple: &Tuple, axis: usize) -> f32 {
+    match axis {
+        0 => tuple.x,
+        1 => tuple.y,
+        _ => tuple.z,
+    }
+}
@@ -0,0 +1,201 @@
+use std::mem;
+
+use crate::{
+    intersection::Intersections,
+    ray::Ray,
+    shape::{Bounds, Shape, bounds::axis_of},
+    tuple::Point,
+};
+
+// Leaves holding this many shapes or fewer are not split further.
+const MAX_LEAF_SIZE: usize = 4;
+
+// A bounding volume hierarchy over a group's children, which are reordered
+// when it is built so that every leaf covers a contiguous run of them. Nodes
+// are stored depth first, so an inner node's left child directly follows it
+// and every node comes before its descendants.
+#[derive(Clone, Debug)]
+pub(super) struct Bvh {
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    bounds: Bounds,
+    kind: NodeKind,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NodeKind {
+    Leaf { start: usize, end: usize },
+    Inner { right: usize },
+}
+
+#[derive(Clone, Copy)]
+struct Item {
+    index: usize,
+    bounds: Bounds,
+    centroid: Point,
+}
+
+impl Bvh {
+    pub(super) fn build(children: &mut Vec<Box<dyn Shape>>) -> Self {
+        let mut items: Vec<Item> = children
+            .iter()
+            .enumerate()
+            .map(|(index, child)| {
+                let bounds = child.bounds();
+                Item {
+                    index,
+                    bounds,
+                    centroid: bounds.centroid(),
+                }
+            })
+            .collect();
+
+        let mut nodes = Vec::new();
+        build_node(&mut nodes, &mut items, 0);
+
+        let mut slots: Vec<_> = mem::take(children).into_iter().map(Some).collect();
+        children.extend(items.iter().filter_map(|item| slots[item.index].take()));
+
+        Self {
+            nodes,
+        }
+    }
+
+    pub(super) fn bounds(&self) -> Bounds {
+        self.nodes[0].bounds
+    }
+
+    // Recomputes every node's bounds after the children have moved, keeping
+    // the existing tree. Children come after their parents, so walking
+    // backwards visits both children of a node before the node itself.
+    pub(super) fn refit(&mut self, children: &[Box<dyn Shape>]) {
+        for idx in (0..self.nodes.len()).rev() {
+            self.nodes[idx].bounds = match self.nodes[idx].kind {
+                NodeKind::Leaf {
+                    start,
+                    end,
+                } => merge_bounds(children[start..end].iter().map(|child| child.bounds())),
+                NodeKind::Inner {
+                    right,
+                } => self.nodes[idx + 1].bounds.merge(&self.nodes[right].bounds),
+            };
+        }
+    }
+
+    pub(super) fn intersect<'a>(
+        &self,
+        children: &'a [Box<dyn Shape>],
+        ray: &Ray,
+        xs: &mut Intersections<'a>,
+    ) {
+        self.intersect_node(0, children, ray, xs);
+    }
+
+    fn intersect_node<'a>(
+        &self,
+        idx: usize,
+        children: &'a [Box<dyn Shape>],
+        ray: &Ray,
+        xs: &mut Intersections<'a>,
+    ) {
+        let node = &self.nodes[idx];
+        if !node.bounds.intersects(ray) {
+            return;
+        }
+
+        match node.kind {
+            NodeKind::Leaf {
+                start,
+                end,
+            } => children[start..end]
+                .iter()
+                .for_each(|child| child.intersect(ray, xs)),
+            NodeKind::Inner {
+                right,
+            } => {
+                self.intersect_node(idx + 1, children, ray, xs);
+                self.intersect_node(right, children, ray, xs);
+            }
+        }
+    }
+}
+
+fn merge_bounds(bounds: impl Iterator<Item = Bounds>) -> Bounds {
+    bounds.fold(Bounds::EMPTY, |acc, b| acc.merge(&b))
+}
+
+// Adds the node for `items`, which start at `offset` among the children, and
+// everything below it. Returns the index of the new node.
+fn build_node(nodes: &mut Vec<Node>, items: &mut [Item], offset: usize) -> usize {
+    let idx = nodes.len();
+    nodes.push(Node {
+        bounds: merge_bounds(items.iter().map(|item| item.bounds)),
+        kind: NodeKind::Leaf {
+            start: offset,
+            end: offset + items.len(),
+        },
+    });
+
+    if items.len() <= MAX_LEAF_SIZE {
+        return idx;
+    }
+    let Some(split) = sah_split(items) else {
+        return idx;
+    };
+
+    let (left, right) = items.split_at_mut(split);
+    build_node(nodes, left, offset);
+    let right = build_node(nodes, right, offset + split);
+    nodes[idx].kind = NodeKind::Inner {
+        right,
+    };
+    idx
+}
+
+// Sorts `items` along the axis their centroids are most spread out on and
+// returns where to split them, chosen by the surface area heuristic: the
+// cost of a split is the area of each side weighted by the number of shapes
+// in it. Returns `None` when the centroids all coincide, as no split would
+// separate anything.
+fn sah_split(items: &mut [Item]) -> Option<usize> {
+    let mut centroids = Bounds::EMPTY;
+    items
+        .iter()
+        .for_each(|item| centroids.add_point(item.centroid));
+
+    let axis = centroids.longest_axis();
+    let extent = centroids.axis_max(axis) - centroids.axis_min(axis);
+    // NaN, from the centroid of unbounded shapes, is rejected as well.
+    if extent.is_nan() || extent <= 0. {
+        return None;
+    }
+
+    items
+        .sort_unstable_by(|a, b| axis_of(&a.centroid, axis).total_cmp(&axis_of(&b.centroid, axis)));
+
+    // right_areas[i] is the area around items[i..].
+    let mut right_areas = vec![0.; items.len()];
+    let mut acc = Bounds::EMPTY;
+    for (area, item) in right_areas.iter_mut().zip(items.iter()).rev() {
+        acc = acc.merge(&item.bounds);
+        *area = acc.surface_area();
+    }
+
+    let mut best = None;
+    let mut left = Bounds::EMPTY;
+    for split in 1..items.len() {
+        left = left.merge(&items[split - 1].bounds);
+        let cost =
+            left.surface_area() * split as f32 + right_areas[split] * (items.len() - split) as f32;
+        if cost.is_finite() && best.is_none_or(|(_, best_cost)| cost < best_cost) {
+            best = Some((split, cost));
+        }
+    }
+
+    // With unbounded shapes every cost is infinite, so fall back to the
+    // median.
+    Some(best.map_or(items.len() / 2, |(split, _)| split))
+}
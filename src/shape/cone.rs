@@ -0,0 +1,114 @@
+use crate::{
+    EPSILON,
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData, cylinder::intersect_caps},
+    tuple::{Point, Vector},
+};
+
+// A double-napped cone around the Y axis with its apex at the origin and a
+// radius equal to |y|, optionally truncated and capped like `Cylinder`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cone {
+    data: ShapeData,
+    pub minimum: f32,
+    pub maximum: f32,
+    pub closed: bool,
+}
+
+impl Cone {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub const fn truncated(mut self, minimum: f32, maximum: f32) -> Self {
+        self.minimum = minimum;
+        self.maximum = maximum;
+        self
+    }
+
+    pub const fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self {
+            data: ShapeData::default(),
+            minimum: f32::NEG_INFINITY,
+            maximum: f32::INFINITY,
+            closed: false,
+        }
+    }
+}
+
+impl Shape for Cone {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        let (o, d) = (ray.origin, ray.direction);
+        let a = d.x * d.x - d.y * d.y + d.z * d.z;
+        let b = 2. * (o.x * d.x - o.y * d.y + o.z * d.z);
+        let c = o.x * o.x - o.y * o.y + o.z * o.z;
+
+        let mut push_if_within = |t: f32| {
+            let y = o.y + t * d.y;
+            if self.minimum < y && y < self.maximum {
+                xs.push(Intersection::new(t, self));
+            }
+        };
+
+        // With `a` at zero the ray is parallel to one of the halves and
+        // crosses the other half exactly once.
+        if a.abs() < EPSILON {
+            if b.abs() >= EPSILON {
+                push_if_within(-c / (2. * b));
+            }
+        } else {
+            // Tangent rays land on a discriminant of zero, which rounding can
+            // push slightly negative.
+            let discriminant = b * b - 4. * a * c;
+            if discriminant >= -EPSILON {
+                let sqrt = discriminant.max(0.).sqrt();
+                push_if_within((-b - sqrt) / (2. * a));
+                push_if_within((-b + sqrt) / (2. * a));
+            }
+        }
+
+        if self.closed {
+            intersect_caps(self, ray, (self.minimum, self.maximum), f32::abs, xs);
+        }
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        let dist = point.x * point.x + point.z * point.z;
+
+        if dist < self.maximum * self.maximum && point.y >= self.maximum - EPSILON {
+            Vector::new(0., 1., 0.)
+        } else if dist < self.minimum * self.minimum && point.y <= self.minimum + EPSILON {
+            Vector::new(0., -1., 0.)
+        } else {
+            let y = dist.sqrt();
+            let y = if point.y > 0. { -y } else { y };
+            Vector::new(point.x, y, point.z)
+        }
+    }
+
+    // The widest point of a cone is at whichever end is furthest from the
+    // apex.
+    fn local_bounds(&self) -> Bounds {
+        let radius = self.minimum.abs().max(self.maximum.abs());
+        Bounds::new(
+            Point::new(-radius, self.minimum, -radius),
+            Point::new(radius, self.maximum, radius),
+        )
+    }
+}
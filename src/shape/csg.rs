@@ -0,0 +1,173 @@
+use crate::{
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData},
+    tuple::{Point, Vector},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsgOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl CsgOperation {
+    // Whether a hit on the left (`lhit`) or right child survives, given
+    // whether the ray is currently inside the left and right children.
+    pub const fn intersection_allowed(self, lhit: bool, inl: bool, inr: bool) -> bool {
+        match self {
+            Self::Union => (lhit && !inr) || (!lhit && !inl),
+            Self::Intersection => (lhit && inr) || (!lhit && inl),
+            Self::Difference => (lhit && !inr) || (!lhit && inl),
+        }
+    }
+}
+
+// Constructive solid geometry: the surface of two shapes combined by a set
+// operation. Like a group, it has no surface of its own and bakes its
+// transform into both children.
+#[derive(Debug)]
+pub struct Csg {
+    data: ShapeData,
+    operation: CsgOperation,
+    // Left, then right.
+    children: [Box<dyn Shape>; 2],
+}
+
+impl Csg {
+    pub fn new(
+        operation: CsgOperation,
+        left: impl Into<Box<dyn Shape>>,
+        right: impl Into<Box<dyn Shape>>,
+    ) -> Self {
+        let mut csg = Self {
+            data: ShapeData::default(),
+            operation,
+            children: [left.into(), right.into()],
+        };
+        csg.update_children();
+        csg
+    }
+
+    pub const fn operation(&self) -> CsgOperation {
+        self.operation
+    }
+
+    pub fn left(&self) -> &dyn Shape {
+        self.children[0].as_ref()
+    }
+
+    pub fn right(&self) -> &dyn Shape {
+        self.children[1].as_ref()
+    }
+
+    // Drops the hits on either child that do not lie on the combined
+    // surface. Hits on other objects are left alone, so this works in place
+    // on a buffer shared with the rest of the scene. Finding which child
+    // each hit belongs to walks both subtrees, so `intersect` avoids this
+    // and keeps each child's hits apart instead.
+    pub fn filter_intersections(&self, xs: &mut Intersections) {
+        let (mut inl, mut inr) = (false, false);
+
+        xs.retain(|i| {
+            let lhit = self.left().includes(i.object);
+            if !lhit && !self.right().includes(i.object) {
+                return true;
+            }
+
+            let allowed = self.operation.intersection_allowed(lhit, inl, inr);
+            if lhit {
+                inl = !inl;
+            } else {
+                inr = !inr;
+            }
+            allowed
+        });
+    }
+
+    // Merges the hits on the left and right children, each sorted by `t`,
+    // into `xs`, keeping only those on the combined surface.
+    fn combine<'a>(
+        &self,
+        left: &[Intersection<'a>],
+        right: &[Intersection<'a>],
+        xs: &mut Intersections<'a>,
+    ) {
+        let (mut left, mut right) = (left.iter().peekable(), right.iter().peekable());
+        let (mut inl, mut inr) = (false, false);
+
+        loop {
+            // On a tie the left hit goes first, as it would in a shared buffer.
+            let lhit = match (left.peek(), right.peek()) {
+                (Some(l), Some(r)) => l.t <= r.t,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let i = if lhit { left.next() } else { right.next() }.expect("peeked a hit");
+
+            if self.operation.intersection_allowed(lhit, inl, inr) {
+                xs.push(*i);
+            }
+            if lhit {
+                inl = !inl;
+            } else {
+                inr = !inr;
+            }
+        }
+    }
+}
+
+impl Shape for Csg {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.data.world_transform();
+        self.children
+            .iter_mut()
+            .for_each(|child| child.set_parent_transform(world_transform));
+    }
+
+    fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    fn build_bvh(&mut self) {
+        self.children.iter_mut().for_each(|child| child.build_bvh());
+    }
+
+    // As with groups, the children already account for this transform.
+    fn intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        let mut left = xs.scratch();
+        let mut right = xs.scratch();
+        self.left().intersect(ray, &mut left);
+        self.right().intersect(ray, &mut right);
+        self.combine(&left, &right, xs);
+        // Last in, first out: each child gets the same buffer next time.
+        xs.recycle(right);
+        xs.recycle(left);
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        self.intersect(&ray.transform(&self.data.world_transform()), xs);
+    }
+
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        panic!("CSG shapes have no surface; normals come from their children")
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        self.bounds().transform(self.data.inverse())
+    }
+
+    fn bounds(&self) -> Bounds {
+        self.left().bounds().merge(&self.right().bounds())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    EPSILON,
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData},
+    tuple::{Point, Vector},
+};
+
+// An axis-aligned cube spanning -1 to 1 on every axis.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cube {
+    data: ShapeData,
+}
+
+impl Cube {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Where a ray enters and leaves the slab between -1 and 1 on one axis.
+fn check_axis(origin: f32, direction: f32) -> (f32, f32) {
+    let tmin_numerator = -1. - origin;
+    let tmax_numerator = 1. - origin;
+
+    let (tmin, tmax) = if direction.abs() >= EPSILON {
+        (tmin_numerator / direction, tmax_numerator / direction)
+    } else {
+        (
+            tmin_numerator * f32::INFINITY,
+            tmax_numerator * f32::INFINITY,
+        )
+    };
+
+    if tmin > tmax {
+        (tmax, tmin)
+    } else {
+        (tmin, tmax)
+    }
+}
+
+impl Shape for Cube {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        let (xtmin, xtmax) = check_axis(ray.origin.x, ray.direction.x);
+        let (ytmin, ytmax) = check_axis(ray.origin.y, ray.direction.y);
+        let (ztmin, ztmax) = check_axis(ray.origin.z, ray.direction.z);
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        if tmin > tmax {
+            return;
+        }
+
+        xs.push(Intersection::new(tmin, self));
+        xs.push(Intersection::new(tmax, self));
+    }
+
+    // The face that was hit is the one along the axis with the largest
+    // absolute coordinate.
+    fn local_normal_at(&self, point: Point) -> Vector {
+        let (x, y, z) = (point.x.abs(), point.y.abs(), point.z.abs());
+        let maxc = x.max(y).max(z);
+
+        if maxc == x {
+            Vector::new(point.x, 0., 0.)
+        } else if maxc == y {
+            Vector::new(0., point.y, 0.)
+        } else {
+            Vector::new(0., 0., point.z)
+        }
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        Bounds::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.))
+    }
+}
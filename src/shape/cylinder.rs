@@ -0,0 +1,127 @@
+use crate::{
+    EPSILON,
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData},
+    tuple::{Point, Vector},
+};
+
+// A unit-radius cylinder around the Y axis, optionally truncated to
+// `minimum..maximum` (exclusive) and capped when `closed`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cylinder {
+    data: ShapeData,
+    pub minimum: f32,
+    pub maximum: f32,
+    pub closed: bool,
+}
+
+impl Cylinder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub const fn truncated(mut self, minimum: f32, maximum: f32) -> Self {
+        self.minimum = minimum;
+        self.maximum = maximum;
+        self
+    }
+
+    pub const fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Self {
+            data: ShapeData::default(),
+            minimum: f32::NEG_INFINITY,
+            maximum: f32::INFINITY,
+            closed: false,
+        }
+    }
+}
+
+// Intersects the end caps at `minimum` and `maximum`, where `radius` gives
+// the cap radius at a given height. An end that is not truncated has no cap.
+pub(super) fn intersect_caps<'a>(
+    object: &'a dyn Shape,
+    ray: &Ray,
+    (minimum, maximum): (f32, f32),
+    radius: impl Fn(f32) -> f32,
+    xs: &mut Intersections<'a>,
+) {
+    if ray.direction.y.abs() < EPSILON {
+        return;
+    }
+
+    for y in [minimum, maximum].into_iter().filter(|y| y.is_finite()) {
+        let t = (y - ray.origin.y) / ray.direction.y;
+        let x = ray.origin.x + t * ray.direction.x;
+        let z = ray.origin.z + t * ray.direction.z;
+        let r = radius(y);
+
+        if x * x + z * z <= r * r + EPSILON {
+            xs.push(Intersection::new(t, object));
+        }
+    }
+}
+
+impl Shape for Cylinder {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        let (o, d) = (ray.origin, ray.direction);
+        let a = d.x * d.x + d.z * d.z;
+
+        // Rays parallel to the Y axis can only hit the caps.
+        if a.abs() >= EPSILON {
+            let b = 2. * (o.x * d.x + o.z * d.z);
+            let c = o.x * o.x + o.z * o.z - 1.;
+            let discriminant = b * b - 4. * a * c;
+
+            if discriminant < 0. {
+                return;
+            }
+
+            let sqrt = discriminant.sqrt();
+            for t in [(-b - sqrt) / (2. * a), (-b + sqrt) / (2. * a)] {
+                let y = o.y + t * d.y;
+                if self.minimum < y && y < self.maximum {
+                    xs.push(Intersection::new(t, self));
+                }
+            }
+        }
+
+        if self.closed {
+            intersect_caps(self, ray, (self.minimum, self.maximum), |_| 1., xs);
+        }
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        let dist = point.x * point.x + point.z * point.z;
+
+        if dist < 1. && point.y >= self.maximum - EPSILON {
+            Vector::new(0., 1., 0.)
+        } else if dist < 1. && point.y <= self.minimum + EPSILON {
+            Vector::new(0., -1., 0.)
+        } else {
+            Vector::new(point.x, 0., point.z)
+        }
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        Bounds::new(
+            Point::new(-1., self.minimum, -1.),
+            Point::new(1., self.maximum, 1.),
+        )
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    intersection::Intersections,
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData, bvh::Bvh},
+    tuple::{Point, Vector},
+};
+
+// A collection of shapes transformed as a unit. Children keep their own
+// transforms, relative to the group. Once `build_bvh` has been called, rays
+// only test the children whose bounding boxes they pass through.
+#[derive(Debug, Default)]
+pub struct Group {
+    data: ShapeData,
+    children: Vec<Box<dyn Shape>>,
+    bvh: Option<Bvh>,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_child(&mut self, child: impl Shape + 'static) {
+        self.add_boxed_child(Box::new(child));
+    }
+
+    // Adding a child discards the hierarchy, which has to be rebuilt.
+    pub fn add_boxed_child(&mut self, mut child: Box<dyn Shape>) {
+        child.set_parent_transform(self.data.world_transform());
+        self.children.push(child);
+        self.bvh = None;
+    }
+
+    pub fn with_child(mut self, child: impl Shape + 'static) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    // The children may be moved through this, so it also discards the
+    // hierarchy.
+    pub fn children_mut(&mut self) -> &mut [Box<dyn Shape>] {
+        self.bvh = None;
+        &mut self.children
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub const fn has_bvh(&self) -> bool {
+        self.bvh.is_some()
+    }
+}
+
+impl Shape for Group {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.data.world_transform();
+        self.children
+            .iter_mut()
+            .for_each(|child| child.set_parent_transform(world_transform));
+
+        if let Some(bvh) = &mut self.bvh {
+            bvh.refit(&self.children);
+        }
+    }
+
+    fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    fn build_bvh(&mut self) {
+        self.children.iter_mut().for_each(|child| child.build_bvh());
+        self.bvh = Some(Bvh::build(&mut self.children));
+    }
+
+    // Children already account for the group's transform, so they are
+    // handed the world-space ray unchanged.
+    fn intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        match &self.bvh {
+            Some(bvh) => bvh.intersect(&self.children, ray, xs),
+            None => self
+                .children
+                .iter()
+                .for_each(|child| child.intersect(ray, xs)),
+        }
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        self.intersect(&ray.transform(&self.data.world_transform()), xs);
+    }
+
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        panic!("Groups have no surface; normals come from their children")
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        self.bounds().transform(self.data.inverse())
+    }
+
+    // The children's bounds are already in world space.
+    fn bounds(&self) -> Bounds {
+        match &self.bvh {
+            Some(bvh) => bvh.bounds(),
+            None => self
+                .children
+                .iter()
+                .fold(Bounds::EMPTY, |acc, child| acc.merge(&child.bounds())),
+        }
+    }
+}
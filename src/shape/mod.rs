@@ -0,0 +1,202 @@
+pub mod bounds;
+mod bvh;
+pub mod cone;
+pub mod csg;
+pub mod cube;
+pub mod cylinder;
+pub mod group;
+pub mod plane;
+pub mod smooth_triangle;
+pub mod sphere;
+pub mod triangle;
+
+use std::{any::Any, fmt, ptr};
+
+pub use bounds::Bounds;
+pub use cone::Cone;
+pub use csg::{Csg, CsgOperation};
+pub use cube::Cube;
+pub use cylinder::Cylinder;
+pub use group::Group;
+pub use plane::Plane;
+pub use smooth_triangle::SmoothTriangle;
+pub use sphere::Sphere;
+pub use triangle::Triangle;
+
+use crate::{
+    intersection::{Intersection, Intersections},
+    material::Material,
+    matrix::Matrix4,
+    ray::Ray,
+    tuple::{Point, Vector},
+};
+
+// State shared by every shape. `parent_transform` is the combined transform
+// of every enclosing group, which groups push down whenever a child is added
+// or an ancestor moves. The inverse of the full object-to-world transform and
+// its transpose are cached, so intersecting and shading never invert a matrix
+// or walk up the hierarchy.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShapeData {
+    transform: Matrix4,
+    parent_transform: Matrix4,
+    inverse: Matrix4,
+    inverse_transpose: Matrix4,
+    material: Material,
+}
+
+impl ShapeData {
+    pub const fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub const fn parent_transform(&self) -> &Matrix4 {
+        &self.parent_transform
+    }
+
+    pub fn world_transform(&self) -> Matrix4 {
+        self.parent_transform * self.transform
+    }
+
+    pub const fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.update_inverse();
+    }
+
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.parent_transform = parent_transform;
+        self.update_inverse();
+    }
+
+    fn update_inverse(&mut self) {
+        self.inverse = self.world_transform().inverse();
+        self.inverse_transpose = self.inverse.transpose();
+    }
+}
+
+// `Any` lets a `dyn Shape` be downcast back to its concrete type.
+pub trait Shape: Any + fmt::Debug + Send + Sync {
+    fn data(&self) -> &ShapeData;
+
+    fn data_mut(&mut self) -> &mut ShapeData;
+
+    // `ray` is already in object space.
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>);
+
+    // `point` is in object space and the returned normal need not be
+    // normalized.
+    fn local_normal_at(&self, point: Point) -> Vector;
+
+    // The box around the shape in object space.
+    fn local_bounds(&self) -> Bounds;
+
+    // The box around the shape in world space, which since transforms are
+    // baked down the hierarchy is also what any enclosing group sees.
+    fn bounds(&self) -> Bounds {
+        self.local_bounds()
+            .transform(&self.data().world_transform())
+    }
+
+    // Shapes whose normal depends on where exactly they were hit, rather than
+    // on the point alone, override this.
+    fn local_normal_at_hit(&self, point: Point, _hit: &Intersection) -> Vector {
+        self.local_normal_at(point)
+    }
+
+    fn transform(&self) -> &Matrix4 {
+        self.data().transform()
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.data_mut().set_transform(transform);
+        self.update_children();
+    }
+
+    fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.data_mut().set_parent_transform(parent_transform);
+        self.update_children();
+    }
+
+    // Called after this shape's world transform changes; shapes that contain
+    // others pass the new transform on to them.
+    fn update_children(&mut self) {}
+
+    fn children(&self) -> &[Box<dyn Shape>] {
+        &[]
+    }
+
+    // Builds bounding volume hierarchies for this shape and everything below
+    // it. Only shapes that contain others have anything to do.
+    fn build_bvh(&mut self) {}
+
+    // Whether `other` is this shape or one of its descendants.
+    fn includes(&self, other: &dyn Shape) -> bool {
+        ptr::addr_eq(self, other) || self.children().iter().any(|child| child.includes(other))
+    }
+
+    fn with_transform(mut self, transform: Matrix4) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_transform(transform);
+        self
+    }
+
+    fn material(&self) -> &Material {
+        &self.data().material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.data_mut().material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.data_mut().material = material;
+    }
+
+    fn with_material(mut self, material: Material) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_material(material);
+        self
+    }
+
+    fn intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        let local_ray = ray.transform(self.data().inverse());
+        self.local_intersect(&local_ray, xs);
+    }
+
+    fn normal_at(&self, world_point: Point) -> Vector {
+        let local_point = self.world_to_object(world_point);
+        let local_normal = self.local_normal_at(local_point);
+        self.normal_to_world(local_normal)
+    }
+
+    fn normal_at_hit(&self, world_point: Point, hit: &Intersection) -> Vector {
+        let local_point = self.world_to_object(world_point);
+        let local_normal = self.local_normal_at_hit(local_point, hit);
+        self.normal_to_world(local_normal)
+    }
+
+    // Both conversions cover the whole parent chain, since the cached inverse
+    // already includes every enclosing group's transform.
+    fn world_to_object(&self, point: Point) -> Point {
+        self.data().inverse * point
+    }
+
+    fn normal_to_world(&self, normal: Vector) -> Vector {
+        let normal = self.data().inverse_transpose * normal;
+        Vector::new(normal.x, normal.y, normal.z).normalize()
+    }
+}
+
+impl<S: Shape + 'static> From<S> for Box<dyn Shape> {
+    fn from(shape: S) -> Self {
+        Box::new(shape)
+    }
+}
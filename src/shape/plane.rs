@@ -0,0 +1,48 @@
+use crate::{
+    EPSILON,
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData},
+    tuple::{Point, Vector},
+};
+
+// An infinite plane through the origin, spanning X and Z.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Plane {
+    data: ShapeData,
+}
+
+impl Plane {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Shape for Plane {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        if ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        xs.push(Intersection::new(-ray.origin.y / ray.direction.y, self));
+    }
+
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        Vector::new(0., 1., 0.)
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        Bounds::new(
+            Point::new(f32::NEG_INFINITY, 0., f32::NEG_INFINITY),
+            Point::new(f32::INFINITY, 0., f32::INFINITY),
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData, triangle::intersect_triangle},
+    tuple::{Point, Vector},
+};
+
+// A triangle with a normal at each vertex. The normal at a hit is blended
+// from the three using the hit's barycentric coordinates, which hides the
+// facets of a coarse mesh.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmoothTriangle {
+    data: ShapeData,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> Self {
+        Self {
+            data: ShapeData::default(),
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+        }
+    }
+
+    pub const fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub const fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub const fn p3(&self) -> Point {
+        self.p3
+    }
+
+    pub const fn n1(&self) -> Vector {
+        self.n1
+    }
+
+    pub const fn n2(&self) -> Vector {
+        self.n2
+    }
+
+    pub const fn n3(&self) -> Vector {
+        self.n3
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        if let Some((t, u, v)) = intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            xs.push(Intersection::with_uv(t, self, u, v));
+        }
+    }
+
+    // Without a hit there is nothing to interpolate with, so fall back to the
+    // flat normal of the face.
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        self.e2.cross(&self.e1)
+    }
+
+    fn local_normal_at_hit(&self, _point: Point, hit: &Intersection) -> Vector {
+        self.n2 * hit.u + self.n3 * hit.v + self.n1 * (1. - hit.u - hit.v)
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        let mut bounds = Bounds::EMPTY;
+        [self.p1, self.p2, self.p3]
+            .into_iter()
+            .for_each(|p| bounds.add_point(p));
+        bounds
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData},
+    tuple::{Point, Vector},
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Sphere {
+    data: ShapeData,
+}
+
+impl Sphere {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Shape for Sphere {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        let sphere_to_ray = ray.origin - Point::new(0., 0., 0.);
+
+        let a = ray.direction.dot(&ray.direction);
+        let b = 2. * ray.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.;
+        let discriminant = b * b - 4. * a * c;
+
+        if discriminant < 0. {
+            return;
+        }
+
+        let sqrt = discriminant.sqrt();
+        xs.push(Intersection::new((-b - sqrt) / (2. * a), self));
+        xs.push(Intersection::new((-b + sqrt) / (2. * a), self));
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        point - Point::new(0., 0., 0.)
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        Bounds::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.))
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    EPSILON,
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData},
+    tuple::{Point, Vector},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Triangle {
+    data: ShapeData,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+        Self {
+            data: ShapeData::default(),
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal: e2.cross(&e1).normalize(),
+        }
+    }
+
+    pub const fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub const fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub const fn p3(&self) -> Point {
+        self.p3
+    }
+
+    pub const fn e1(&self) -> Vector {
+        self.e1
+    }
+
+    pub const fn e2(&self) -> Vector {
+        self.e2
+    }
+
+    pub const fn normal(&self) -> Vector {
+        self.normal
+    }
+}
+
+// Möller–Trumbore: solves for the distance along the ray together with the
+// barycentric coordinates of the hit, relative to `p1` and the two edges
+// leaving it.
+pub(super) fn intersect_triangle(
+    p1: Point,
+    e1: Vector,
+    e2: Vector,
+    ray: &Ray,
+) -> Option<(f32, f32, f32)> {
+    let dir_cross_e2 = ray.direction.cross(&e2);
+    let det = e1.dot(&dir_cross_e2);
+
+    // The ray is parallel to the triangle's plane. The determinant grows
+    // with the edges and the direction, so the threshold does too, or small
+    // triangles would never be hit.
+    let scale = e1.magnitude() * e2.magnitude() * ray.direction.magnitude();
+    if det.abs() <= EPSILON * scale {
+        return None;
+    }
+
+    let f = 1. / det;
+    let p1_to_origin = ray.origin - p1;
+    let u = f * p1_to_origin.dot(&dir_cross_e2);
+    if !(0. ..=1.).contains(&u) {
+        return None;
+    }
+
+    let origin_cross_e1 = p1_to_origin.cross(&e1);
+    let v = f * ray.direction.dot(&origin_cross_e1);
+    if v < 0. || u + v > 1. {
+        return None;
+    }
+
+    Some((f * e2.dot(&origin_cross_e1), u, v))
+}
+
+impl Shape for Triangle {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        if let Some((t, u, v)) = intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            xs.push(Intersection::with_uv(t, self, u, v));
+        }
+    }
+
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        self.normal
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        let mut bounds = Bounds::EMPTY;
+        [self.p1, self.p2, self.p3]
+            .into_iter()
+            .for_each(|p| bounds.add_point(p));
+        bounds
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, Deref, Mul, Sub};
+use std::ops::{Add, Deref, Mul, Neg, Sub};
 
 use crate::tuple::inner::Tuple;
 
@@ -33,6 +33,10 @@ impl Vector {
             self.x.mul_add(other.y, -self.y * other.x),
         )
     }
+
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * 2. * self.dot(normal)
+    }
 }
 
 impl Deref for Vector {
@@ -77,6 +81,14 @@ impl Sub for Vector {
     }
 }
 
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
 impl Mul<f32> for Vector {
     type Output = Self;
 
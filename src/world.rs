@@ -0,0 +1,167 @@
+use crate::{
+    color::Color,
+    intersection::{Computations, Intersections},
+    light::PointLight,
+    material::lighting,
+    ray::Ray,
+    shape::Shape,
+    tuple::Point,
+};
+
+#[derive(Debug)]
+pub struct World {
+    pub objects: Vec<Box<dyn Shape>>,
+    pub lights: Vec<PointLight>,
+    // How many times a ray may bounce off reflective surfaces, which stops
+    // facing mirrors from recursing forever.
+    pub max_depth: u32,
+}
+
+impl World {
+    pub const DEFAULT_MAX_DEPTH: u32 = 5;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub const fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn with_object(mut self, object: impl Shape + 'static) -> Self {
+        self.objects.push(Box::new(object));
+        self
+    }
+
+    pub fn with_light(mut self, light: PointLight) -> Self {
+        self.lights.push(light);
+        self
+    }
+
+    // Clears `xs` and refills it, so one buffer can be reused for every ray.
+    pub fn intersect<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        xs.clear();
+        self.objects
+            .iter()
+            .for_each(|object| object.intersect(ray, xs));
+    }
+
+    pub fn shade_hit<'a>(
+        &'a self,
+        comps: &Computations,
+        remaining: u32,
+        xs: &mut Intersections<'a>,
+    ) -> Color {
+        let surface = self.lights.iter().fold(Color::BLACK, |color, light| {
+            let in_shadow = self.is_shadowed(light.position, comps.over_point, xs);
+            color
+                + lighting(
+                    comps.object.material(),
+                    comps.object,
+                    light,
+                    comps.over_point,
+                    comps.eyev,
+                    comps.normalv,
+                    in_shadow,
+                )
+        });
+
+        let reflected = self.reflected_color(comps, remaining, xs);
+        let refracted = self.refracted_color(comps, remaining, xs);
+
+        let material = comps.object.material();
+        if material.reflective > 0. && material.transparency > 0. {
+            let reflectance = comps.schlick();
+            surface + reflected * reflectance + refracted * (1. - reflectance)
+        } else {
+            surface + reflected + refracted
+        }
+    }
+
+    pub fn color_at<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) -> Color {
+        self.color_at_depth(ray, self.max_depth, xs)
+    }
+
+    // Everything needed from `xs` is copied into the computations before
+    // shading, so the same buffer is safely reused by the recursive rays.
+    fn color_at_depth<'a>(
+        &'a self,
+        ray: &Ray,
+        remaining: u32,
+        xs: &mut Intersections<'a>,
+    ) -> Color {
+        self.intersect(ray, xs);
+        match xs.hit() {
+            Some(hit) => {
+                let comps = hit.prepare_computations(ray, xs);
+                self.shade_hit(&comps, remaining, xs)
+            }
+            None => Color::BLACK,
+        }
+    }
+
+    pub fn reflected_color<'a>(
+        &'a self,
+        comps: &Computations,
+        remaining: u32,
+        xs: &mut Intersections<'a>,
+    ) -> Color {
+        let reflective = comps.object.material().reflective;
+        if remaining == 0 || reflective == 0. {
+            return Color::BLACK;
+        }
+
+        let reflect_ray = Ray::new(comps.over_point, comps.reflectv);
+        self.color_at_depth(&reflect_ray, remaining - 1, xs) * reflective
+    }
+
+    pub fn refracted_color<'a>(
+        &'a self,
+        comps: &Computations,
+        remaining: u32,
+        xs: &mut Intersections<'a>,
+    ) -> Color {
+        let transparency = comps.object.material().transparency;
+        if remaining == 0 || transparency == 0. {
+            return Color::BLACK;
+        }
+
+        // Snell's law; past the critical angle all light is reflected.
+        let n_ratio = comps.n1 / comps.n2;
+        let cos_i = comps.eyev.dot(&comps.normalv);
+        let sin2_t = n_ratio * n_ratio * (1. - cos_i * cos_i);
+        if sin2_t > 1. {
+            return Color::BLACK;
+        }
+
+        let cos_t = (1. - sin2_t).sqrt();
+        let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
+        let refract_ray = Ray::new(comps.under_point, direction);
+        self.color_at_depth(&refract_ray, remaining - 1, xs) * transparency
+    }
+
+    pub fn is_shadowed<'a>(
+        &'a self,
+        light_position: Point,
+        point: Point,
+        xs: &mut Intersections<'a>,
+    ) -> bool {
+        let v = light_position - point;
+        let distance = v.magnitude();
+        let ray = Ray::new(point, v.normalize());
+
+        self.intersect(&ray, xs);
+        xs.hit().is_some_and(|hit| hit.t < distance)
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self {
+            objects: Vec::new(),
+            lights: Vec::new(),
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::f32::consts::{FRAC_PI_4, SQRT_2};
+
+use ray_tracer::{
+    matrix::Matrix4,
+    ray::Ray,
+    shape::{
+        Bounds, Cone, Csg, CsgOperation, Cube, Cylinder, Group, Plane, Shape, SmoothTriangle,
+        Sphere, Triangle,
+    },
+    tuple::{Point, Vector},
+};
+
+const INF: f32 = f32::INFINITY;
+
+fn bounds(min: (f32, f32, f32), max: (f32, f32, f32)) -> Bounds {
+    Bounds::new(
+        Point::new(min.0, min.1, min.2),
+        Point::new(max.0, max.1, max.2),
+    )
+}
+
+#[test]
+fn empty_bounds() {
+    let b = Bounds::default();
+    assert!(b.is_empty());
+    assert_eq!(b, Bounds::EMPTY);
+    assert_eq!(b.surface_area(), 0.);
+}
+
+#[test]
+fn add_points() {
+    let mut b = Bounds::EMPTY;
+    b.add_point(Point::new(-5., 2., 0.));
+    b.add_point(Point::new(7., 0., -3.));
+    assert_eq!(b, bounds((-5., 0., -3.), (7., 2., 0.)));
+}
+
+#[test]
+fn merge_bounds() {
+    let b1 = bounds((-5., -2., 0.), (7., 4., 4.));
+    let b2 = bounds((8., -7., -2.), (14., 2., 8.));
+    assert_eq!(b1.merge(&b2), bounds((-5., -7., -2.), (14., 4., 8.)));
+    assert_eq!(Bounds::EMPTY.merge(&b1), b1);
+}
+
+#[test]
+fn contains_point() {
+    let b = bounds((5., -2., 0.), (11., 4., 7.));
+    let cases = [
+        (Point::new(5., -2., 0.), true),
+        (Point::new(11., 4., 7.), true),
+        (Point::new(8., 1., 3.), true),
+        (Point::new(3., 0., 3.), false),
+        (Point::new(8., -4., 3.), false),
+        (Point::new(8., 1., -1.), false),
+        (Point::new(13., 1., 3.), false),
+        (Point::new(8., 5., 3.), false),
+        (Point::new(8., 1., 8.), false),
+    ];
+    for (point, expected) in cases {
+        assert_eq!(b.contains_point(point), expected, "{point:?}");
+    }
+}
+
+#[test]
+fn contains_bounds() {
+    let b = bounds((5., -2., 0.), (11., 4., 7.));
+    assert!(b.contains_bounds(&bounds((5., -2., 0.), (11., 4., 7.))));
+    assert!(b.contains_bounds(&bounds((6., -1., 1.), (10., 3., 6.))));
+    assert!(!b.contains_bounds(&bounds((4., -3., -1.), (10., 3., 6.))));
+    assert!(!b.contains_bounds(&bounds((6., -1., 1.), (12., 5., 8.))));
+}
+
+#[test]
+fn centroid_area_and_axis() {
+    let b = bounds((-1., -2., -3.), (1., 4., 3.));
+    assert_eq!(b.centroid(), Point::new(0., 1., 0.));
+    assert_eq!(b.surface_area(), 2. * (2. * 6. + 6. * 6. + 6. * 2.));
+    assert_eq!(b.longest_axis(), 1);
+}
+
+#[test]
+fn transform_bounds() {
+    let b = bounds((-1., -1., -1.), (1., 1., 1.));
+    let m = Matrix4::rotation_y(FRAC_PI_4).rotate_x(FRAC_PI_4);
+    assert_eq!(
+        b.transform(&m),
+        bounds((-SQRT_2, -1.70711, -1.70711), (SQRT_2, 1.70711, 1.70711))
+    );
+}
+
+#[test]
+fn transform_infinite_bounds() {
+    let plane = bounds((-INF, 0., -INF), (INF, 0., INF));
+    assert_eq!(
+        plane.transform(&Matrix4::translation(1., 2., 3.)),
+        bounds((-INF, 2., -INF), (INF, 2., INF))
+    );
+    assert_eq!(
+        plane.transform(&Matrix4::rotation_y(FRAC_PI_4)),
+        bounds((-INF, 0., -INF), (INF, 0., INF))
+    );
+}
+
+#[test]
+fn ray_intersects_bounds() {
+    let b = bounds((5., -2., 0.), (11., 4., 7.));
+    let cases = [
+        (Point::new(15., 1., 2.), Vector::new(-1., 0., 0.), true),
+        (Point::new(-5., -1., 4.), Vector::new(1., 0., 0.), true),
+        (Point::new(7., 6., 5.), Vector::new(0., -1., 0.), true),
+        (Point::new(9., -5., 6.), Vector::new(0., 1., 0.), true),
+        (Point::new(8., 2., 12.), Vector::new(0., 0., -1.), true),
+        (Point::new(6., 0., -5.), Vector::new(0., 0., 1.), true),
+        (Point::new(8., 1., 3.5), Vector::new(0., 0., 1.), true),
+        (Point::new(9., -1., -8.), Vector::new(2., 4., 6.), false),
+        (Point::new(8., 3., -4.), Vector::new(6., 2., 4.), false),
+        (Point::new(9., -1., -2.), Vector::new(4., 6., 2.), false),
+        (Point::new(4., 0., 9.), Vector::new(0., 0., -1.), false),
+        (Point::new(8., 6., -1.), Vector::new(0., -1., 0.), false),
+        (Point::new(12., 5., 4.), Vector::new(-1., 0., 0.), false),
+        (Point::new(8., 1., 10.), Vector::new(0., 0., 1.), false),
+    ];
+    for (origin, direction, expected) in cases {
+        let r = Ray::new(origin, direction.normalize());
+        assert_eq!(b.intersects(&r), expected, "{origin:?} {direction:?}");
+    }
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(1., 0., 0.));
+    assert!(!Bounds::EMPTY.intersects(&r));
+}
+
+#[test]
+fn primitive_bounds() {
+    let unit = bounds((-1., -1., -1.), (1., 1., 1.));
+    assert_eq!(Sphere::new().local_bounds(), unit);
+    assert_eq!(Cube::new().local_bounds(), unit);
+    assert_eq!(
+        Plane::new().local_bounds(),
+        bounds((-INF, 0., -INF), (INF, 0., INF))
+    );
+    assert_eq!(
+        Cylinder::new().local_bounds(),
+        bounds((-1., -INF, -1.), (1., INF, 1.))
+    );
+    assert_eq!(
+        Cylinder::new().truncated(-5., 3.).local_bounds(),
+        bounds((-1., -5., -1.), (1., 3., 1.))
+    );
+    assert_eq!(
+        Cone::new().local_bounds(),
+        bounds((-INF, -INF, -INF), (INF, INF, INF))
+    );
+    assert_eq!(
+        Cone::new().truncated(-5., 3.).local_bounds(),
+        bounds((-5., -5., -5.), (5., 3., 5.))
+    );
+
+    let (p1, p2, p3) = (
+        Point::new(-3., 7., 2.),
+        Point::new(6., 2., -4.),
+        Point::new(2., -1., -1.),
+    );
+    let expected = bounds((-3., -1., -4.), (6., 7., 2.));
+    assert_eq!(Triangle::new(p1, p2, p3).local_bounds(), expected);
+    let n = Vector::new(0., 1., 0.);
+    assert_eq!(
+        SmoothTriangle::new(p1, p2, p3, n, n, n).local_bounds(),
+        expected
+    );
+}
+
+#[test]
+fn shape_bounds_are_in_world_space() {
+    let s = Sphere::new().with_transform(Matrix4::scaling(0.5, 2., 4.).translate(1., -3., 5.));
+    assert_eq!(s.bounds(), bounds((0.5, -5., 1.), (1.5, -1., 9.)));
+}
+
+#[test]
+fn group_bounds() {
+    let s = Sphere::new().with_transform(Matrix4::scaling(2., 2., 2.).translate(2., 5., -3.));
+    let c = Cylinder::new()
+        .truncated(-2., 2.)
+        .with_transform(Matrix4::scaling(0.5, 1., 0.5).translate(-4., -1., 4.));
+    let g = Group::new().with_child(s).with_child(c);
+    assert_eq!(g.bounds(), bounds((-4.5, -3., -5.), (4., 7., 4.5)));
+    assert_eq!(g.local_bounds(), g.bounds());
+
+    let g = g.with_transform(Matrix4::translation(1., 0., 0.));
+    assert_eq!(g.bounds(), bounds((-3.5, -3., -5.), (5., 7., 4.5)));
+    assert_eq!(g.local_bounds(), bounds((-4.5, -3., -5.), (4., 7., 4.5)));
+}
+
+#[test]
+fn csg_bounds() {
+    let left = Sphere::new();
+    let right = Sphere::new().with_transform(Matrix4::translation(2., 3., 4.));
+    let c = Csg::new(CsgOperation::Difference, left, right);
+    assert_eq!(c.bounds(), bounds((-1., -1., -1.), (3., 4., 5.)));
+}
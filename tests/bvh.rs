@@ -0,0 +1,132 @@
+use std::ptr;
+
+use ray_tracer::{
+    intersection::Intersections,
+    matrix::Matrix4,
+    ray::Ray,
+    shape::{Bounds, Group, Plane, Shape, Sphere},
+    tuple::{Point, Vector},
+};
+
+// A row of `n` spheres along x, two units apart.
+fn row_of_spheres(n: usize) -> Group {
+    (0..n).fold(Group::new(), |g, i| {
+        g.with_child(Sphere::new().with_transform(Matrix4::translation(i as f32 * 2., 0., 0.)))
+    })
+}
+
+fn hits(g: &Group, ray: &Ray) -> Vec<(f32, *const ())> {
+    let mut xs = Intersections::new();
+    g.intersect(ray, &mut xs);
+    xs.iter()
+        .map(|i| (i.t, ptr::from_ref(i.object).cast()))
+        .collect()
+}
+
+#[test]
+fn build_bvh() {
+    let mut g = row_of_spheres(20);
+    assert!(!g.has_bvh());
+    let before = g.bounds();
+    g.build_bvh();
+    assert!(g.has_bvh());
+    assert_eq!(g.bounds(), before);
+    assert_eq!(g.children().len(), 20);
+}
+
+#[test]
+fn bvh_gives_same_hits() {
+    let mut g = row_of_spheres(20);
+    let rays: Vec<Ray> = (0..40)
+        .map(|i| {
+            Ray::new(
+                Point::new(i as f32 - 1.5, 0.3, -5.),
+                Vector::new(0.1, 0., 1.).normalize(),
+            )
+        })
+        .chain([Ray::new(Point::new(-5., 0., 0.), Vector::new(1., 0., 0.))])
+        .collect();
+
+    let mut before: Vec<_> = rays.iter().map(|r| hits(&g, r)).collect();
+    g.build_bvh();
+    let after: Vec<_> = rays.iter().map(|r| hits(&g, r)).collect();
+
+    // Building reorders the children but never their hits; only the order of
+    // hits at equal distance could change, and none are equal here.
+    before
+        .iter_mut()
+        .for_each(|h| h.sort_by(|a, b| a.0.total_cmp(&b.0)));
+    assert_eq!(before, after);
+    assert_eq!(after.last().unwrap().len(), 40);
+}
+
+#[test]
+fn bvh_skips_missed_subtrees() {
+    let mut g = row_of_spheres(20);
+    g.build_bvh();
+    let r = Ray::new(Point::new(0., 5., -5.), Vector::new(0., 0., 1.));
+    assert!(hits(&g, &r).is_empty());
+}
+
+#[test]
+fn bvh_follows_group_transform() {
+    let mut g = row_of_spheres(20);
+    g.build_bvh();
+    g.set_transform(Matrix4::translation(0., 10., 0.));
+
+    assert_eq!(
+        g.bounds(),
+        Bounds::new(Point::new(-1., 9., -1.), Point::new(39., 11., 1.))
+    );
+    let r = Ray::new(Point::new(0., 10., -5.), Vector::new(0., 0., 1.));
+    assert_eq!(hits(&g, &r).len(), 2);
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    assert!(hits(&g, &r).is_empty());
+}
+
+#[test]
+fn changing_children_discards_bvh() {
+    let mut g = row_of_spheres(20);
+    g.build_bvh();
+    g.add_child(Sphere::new().with_transform(Matrix4::translation(0., 10., 0.)));
+    assert!(!g.has_bvh());
+    let r = Ray::new(Point::new(0., 10., -5.), Vector::new(0., 0., 1.));
+    assert_eq!(hits(&g, &r).len(), 2);
+
+    g.build_bvh();
+    g.children_mut()[0].set_transform(Matrix4::translation(0., -10., 0.));
+    assert!(!g.has_bvh());
+}
+
+#[test]
+fn bvh_built_for_nested_groups() {
+    let mut g = Group::new()
+        .with_child(row_of_spheres(10))
+        .with_child(row_of_spheres(10).with_transform(Matrix4::translation(0., 5., 0.)));
+    g.build_bvh();
+
+    let r = Ray::new(Point::new(4., 5., -5.), Vector::new(0., 0., 1.));
+    assert_eq!(hits(&g, &r).len(), 2);
+}
+
+#[test]
+fn bvh_with_unbounded_children() {
+    let mut g = row_of_spheres(10);
+    g.add_child(Plane::new().with_transform(Matrix4::translation(0., -1., 0.)));
+    g.add_child(Plane::new().with_transform(Matrix4::translation(0., 1., 0.)));
+    g.build_bvh();
+
+    let r = Ray::new(Point::new(100., 5., 0.), Vector::new(0., -1., 0.));
+    assert_eq!(hits(&g, &r).len(), 2);
+    let r = Ray::new(Point::new(6., 5., 0.), Vector::new(0., -1., 0.));
+    assert_eq!(hits(&g, &r).len(), 4);
+}
+
+#[test]
+fn bvh_of_empty_group() {
+    let mut g = Group::new();
+    g.build_bvh();
+    assert!(g.bounds().is_empty());
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    assert!(hits(&g, &r).is_empty());
+}
@@ -73,3 +73,122 @@ fn ppm_terminates_with_newline() {
     let ppm = c.to_ppm();
     assert_eq!(ppm.lines().last().unwrap(), "");
 }
+
+#[test]
+fn p3_rows_wrap_and_start_on_new_lines() {
+    let mut c = Canvas::new(10, 2);
+    c.pixels.fill(Color::new(1., 0.8, 0.6));
+    c.write_pixel(3, 1, Color::new(0.1, 0.2, 0.4));
+
+    let mut out = Vec::new();
+    c.write_p3(&mut out, 255).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            "P3\n10 2\n255\n",
+            "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204\n",
+            "153 255 204 153 255 204 153 255 204 153 255 204 153\n",
+            "255 204 153 255 204 153 255 204 153 26 51 102 255 204 153 255 204 153\n",
+            "255 204 153 255 204 153 255 204 153 255 204 153\n",
+            "\n",
+        )
+    );
+}
+
+#[test]
+fn p3_with_16_bit_maxval() {
+    let mut c = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(1., 0.5, 0.));
+    c.write_pixel(1, 0, Color::new(2., -1., 0.25));
+
+    let mut out = Vec::new();
+    c.write_p3(&mut out, 65535).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "P3\n2 1\n65535\n65535 32768 0 65535 0 16384\n\n"
+    );
+}
+
+#[test]
+fn p3_wraps_wide_values_at_70_columns() {
+    let mut c = Canvas::new(10, 1);
+    c.pixels.fill(Color::new(1., 1., 1.));
+
+    let mut out = Vec::new();
+    c.write_p3(&mut out, 65535).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<_> = out.lines().skip(3).collect();
+    assert!(lines.iter().all(|line| line.len() <= 70));
+    assert_eq!(lines[0].split(' ').count(), 11);
+    assert_eq!(
+        lines
+            .iter()
+            .map(|l| l.split_whitespace().count())
+            .sum::<usize>(),
+        30
+    );
+}
+
+#[test]
+fn write_p6() {
+    let mut c = Canvas::new(2, 2);
+    c.write_pixel(0, 0, Color::new(1., 0., 0.));
+    c.write_pixel(1, 0, Color::new(0., 0.5, 0.));
+    c.write_pixel(1, 1, Color::new(0.2, 0.4, 1.5));
+
+    let mut out = Vec::new();
+    c.write_p6(&mut out, 255).unwrap();
+    let (header, data) = out.split_at(11);
+    assert_eq!(header, b"P6\n2 2\n255\n");
+    assert_eq!(data, [255, 0, 0, 0, 128, 0, 0, 0, 0, 51, 102, 255]);
+}
+
+#[test]
+fn write_p6_with_16_bit_maxval() {
+    let mut c = Canvas::new(1, 1);
+    c.write_pixel(0, 0, Color::new(1., 0.5, 0.));
+
+    let mut out = Vec::new();
+    c.write_p6(&mut out, 1000).unwrap();
+    let (header, data) = out.split_at(12);
+    assert_eq!(header, b"P6\n1 1\n1000\n");
+    assert_eq!(data, [0x03, 0xE8, 0x01, 0xF4, 0, 0]);
+}
+
+#[test]
+fn zero_maxval_is_rejected() {
+    let c = Canvas::new(1, 1);
+    let err = c.write_p6(Vec::new(), 0).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(c.write_p3(Vec::new(), 0).is_err());
+}
+
+// Records the size of every write, to check output is streamed rather than
+// built up in memory first.
+#[derive(Default)]
+struct WriteSizes(Vec<usize>);
+
+impl std::io::Write for WriteSizes {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.push(buf.len());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writers_stream_row_by_row() {
+    let c = Canvas::new(100, 50);
+
+    let mut sizes = WriteSizes::default();
+    c.write_p6(&mut sizes, 255).unwrap();
+    assert!(sizes.0.iter().all(|&size| size <= 300));
+    assert_eq!(sizes.0.iter().filter(|&&size| size == 300).count(), 50);
+
+    let mut sizes = WriteSizes::default();
+    c.write_p3(&mut sizes, 255).unwrap();
+    assert!(sizes.0.iter().all(|&size| size <= 71));
+}
@@ -34,3 +34,44 @@ fn mul_colors() {
     let c2 = Color::new(0.9, 1., 0.1);
     assert_eq!(c1 * c2, Color::new(0.9, 0.2, 0.04));
 }
+
+#[test]
+fn quantize_color() {
+    let c = Color::new(1.5, 0.5, -0.2);
+    assert_eq!(c.quantize(255), [255, 128, 0]);
+    assert_eq!(c.quantize(65535), [65535, 32768, 0]);
+    assert_eq!(c.quantize(1), [1, 1, 0]);
+    assert_eq!(c.to_rgb8(), [255, 128, 0]);
+}
+
+#[test]
+fn rgbe_encoding() {
+    assert_eq!(Color::new(1., 0.5, 0.25).to_rgbe(), [128, 64, 32, 129]);
+    assert_eq!(Color::new(1000., 0., 3.).to_rgbe(), [250, 0, 1, 138]);
+    assert_eq!(Color::new(-1., f32::NAN, 0.).to_rgbe(), [0; 4]);
+    assert_eq!(Color::new(f32::INFINITY, 0., 0.).to_rgbe(), [255; 4]);
+    // Rounding up to a whole power of two moves to the next exponent.
+    assert_eq!(Color::new(0.999, 0., 0.).to_rgbe(), [128, 0, 0, 129]);
+
+    assert_eq!(
+        Color::from_rgbe([128, 64, 32, 129]),
+        Color::new(1., 0.5, 0.25)
+    );
+    assert_eq!(Color::from_rgbe([200, 10, 0, 0]), Color::BLACK);
+}
+
+#[test]
+fn rgbe_round_trip_keeps_dynamic_range() {
+    for c in [
+        Color::new(0.3, 0.6, 0.9),
+        Color::new(123.4, 56.7, 8.9),
+        Color::new(1e-6, 2e-6, 3e-6),
+        Color::new(5e4, 0., 1e3),
+    ] {
+        let decoded = Color::from_rgbe(c.to_rgbe());
+        let brightest = c.r.max(c.g).max(c.b);
+        for (a, b) in [(decoded.r, c.r), (decoded.g, c.g), (decoded.b, c.b)] {
+            assert!((a - b).abs() <= brightest / 256., "{c:?} -> {decoded:?}");
+        }
+    }
+}
@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+
+use ray_tracer::{
+    color::Color,
+    light::PointLight,
+    material::Material,
+    matrix::Matrix4,
+    shape::{Shape, Sphere},
+    tuple::Point,
+    world::World,
+};
+
+pub fn default_world() -> World {
+    let light = PointLight::new(Point::new(-10., 10., -10.), Color::new(1., 1., 1.));
+    let outer = Sphere::new().with_material(Material {
+        color: Color::new(0.8, 1.0, 0.6),
+        diffuse: 0.7,
+        specular: 0.2,
+        ..Material::default()
+    });
+    let inner = Sphere::new().with_transform(Matrix4::scaling(0.5, 0.5, 0.5));
+
+    World::new()
+        .with_light(light)
+        .with_object(outer)
+        .with_object(inner)
+}
+
+pub fn glass_sphere() -> Sphere {
+    Sphere::new().with_material(Material {
+        transparency: 1.,
+        refractive_index: 1.5,
+        ..Material::default()
+    })
+}
@@ -0,0 +1,172 @@
+use ray_tracer::{
+    compress::{InflateError, adler32, crc32, deflate, inflate, zlib_compress, zlib_decompress},
+    rng::Rng,
+};
+
+// Larger than anything these tests decompress.
+const LIMIT: usize = 1 << 20;
+
+#[test]
+fn crc32_check_values() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+}
+
+#[test]
+fn adler32_check_values() {
+    assert_eq!(adler32(b""), 1);
+    assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    // Long enough to need the modulus reductions.
+    assert_eq!(adler32(&[0xFF; 100_000]), 0x149A_302C);
+}
+
+// Streams produced by zlib, one for each block type.
+#[test]
+fn decompress_stored_block() {
+    let stream = [
+        120, 1, 1, 5, 0, 250, 255, 104, 101, 108, 108, 111, 6, 44, 2, 21,
+    ];
+    assert_eq!(zlib_decompress(&stream, LIMIT).unwrap(), b"hello");
+}
+
+#[test]
+fn decompress_fixed_block() {
+    let stream = [
+        120, 218, 75, 76, 42, 74, 76, 78, 76, 73, 4, 82, 58, 10, 197, 137, 153, 41, 10, 37, 25,
+        169, 10, 137, 137, 69, 41, 101, 137, 69, 217, 10, 37, 249, 16, 126, 82, 82, 126, 137, 53,
+        80, 24, 21, 36, 233, 41, 36, 14, 113, 253, 0, 203, 59, 90, 213,
+    ];
+    let expected = b"abracadabra, said the aardvark to the abbot; aaaaaaaaaaaaaaaab. ".repeat(4);
+    assert_eq!(zlib_decompress(&stream, LIMIT).unwrap(), expected);
+}
+
+#[test]
+fn decompress_dynamic_block() {
+    let stream = [
+        120, 218, 237, 202, 49, 1, 0, 48, 12, 195, 48, 172, 142, 19, 254, 20, 198, 98, 87, 117,
+        139, 108, 33, 96, 132, 218, 164, 78, 215, 89, 70, 11, 87, 254, 149, 7, 252, 220, 154, 111,
+    ];
+    let expected: Vec<u8> = (0..400)
+        .map(|i| b'a' + ((i * i * 31 + i / 7) % 5) as u8)
+        .collect();
+    assert_eq!(zlib_decompress(&stream, LIMIT).unwrap(), expected);
+}
+
+fn round_trip(data: &[u8]) {
+    let compressed = zlib_compress(data);
+    assert_eq!(zlib_decompress(&compressed, LIMIT).unwrap(), data);
+
+    let raw = deflate(data);
+    assert_eq!(inflate(&raw, LIMIT).unwrap(), (data.to_vec(), raw.len()));
+}
+
+#[test]
+fn round_trip_data() {
+    round_trip(b"");
+    round_trip(b"a");
+    round_trip(b"abc");
+    round_trip(&b"The quick brown fox jumps over the lazy dog. ".repeat(50));
+    round_trip(&(0..=255).collect::<Vec<u8>>());
+
+    let mut rng = Rng::new(9);
+    let noise: Vec<u8> = (0..10_000).map(|_| rng.next_u32() as u8).collect();
+    round_trip(&noise);
+
+    // Few symbols, so lots of short and overlapping matches.
+    let sparse: Vec<u8> = (0..50_000).map(|_| (rng.next_u32() % 3) as u8).collect();
+    round_trip(&sparse);
+}
+
+#[test]
+fn long_runs_and_distant_repeats() {
+    round_trip(&[0; 100_000]);
+
+    // A block repeated just inside and just outside the window.
+    let mut rng = Rng::new(4);
+    let block: Vec<u8> = (0..1000).map(|_| rng.next_u32() as u8).collect();
+    for gap in [32 * 1024 - 1000, 32 * 1024 - 999, 40_000] {
+        let mut data = block.clone();
+        data.extend((0..gap).map(|i| (i % 251) as u8));
+        data.extend(&block);
+        round_trip(&data);
+    }
+}
+
+#[test]
+fn repeats_compress() {
+    let data = b"abcdefgh".repeat(1000);
+    assert!(zlib_compress(&data).len() < 100);
+    assert!(zlib_compress(&[7; 100_000]).len() < 1000);
+}
+
+#[test]
+fn corrupt_streams() {
+    let good = zlib_compress(b"some data to break in various ways");
+
+    assert_eq!(zlib_decompress(&[], LIMIT), Err(InflateError::Truncated));
+    assert_eq!(
+        zlib_decompress(&[0x78, 0x02], LIMIT),
+        Err(InflateError::InvalidHeader)
+    );
+    assert_eq!(
+        zlib_decompress(&[0x79, 0x01 ^ 0x18], LIMIT),
+        Err(InflateError::InvalidHeader)
+    );
+    assert_eq!(
+        zlib_decompress(&[0x78, 0xBB, 0, 0, 0, 0], LIMIT),
+        Err(InflateError::PresetDictionary)
+    );
+
+    assert_eq!(
+        zlib_decompress(&good[..good.len() - 6], LIMIT),
+        Err(InflateError::Truncated)
+    );
+    assert_eq!(
+        zlib_decompress(&good[..good.len() - 2], LIMIT),
+        Err(InflateError::Truncated)
+    );
+
+    let mut bad_checksum = good.clone();
+    *bad_checksum.last_mut().unwrap() ^= 1;
+    assert_eq!(
+        zlib_decompress(&bad_checksum, LIMIT),
+        Err(InflateError::ChecksumMismatch)
+    );
+
+    // Final block of type 3.
+    assert_eq!(
+        inflate(&[0b111], LIMIT),
+        Err(InflateError::InvalidBlockType)
+    );
+    // Stored block whose length and complement disagree.
+    assert_eq!(
+        inflate(&[1, 5, 0, 0, 0], LIMIT),
+        Err(InflateError::InvalidStoredLength)
+    );
+    // Fixed block starting with a back-reference: length symbol 257
+    // (0000001), distance code 0 (00000).
+    assert_eq!(
+        inflate(&[0x03, 0x02, 0x00], LIMIT),
+        Err(InflateError::InvalidDistance)
+    );
+}
+
+#[test]
+fn output_is_limited() {
+    let data = [0; 100_000];
+    let compressed = zlib_compress(&data);
+    assert!(compressed.len() < 1000);
+    assert_eq!(zlib_decompress(&compressed, data.len()).unwrap(), data);
+    assert_eq!(
+        zlib_decompress(&compressed, data.len() - 1),
+        Err(InflateError::TooLarge)
+    );
+
+    // Literals only, and a stored block.
+    let raw = deflate(b"abc");
+    assert_eq!(inflate(&raw, 2), Err(InflateError::TooLarge));
+    let stored = [1, 5, 0, 250, 255, b'h', b'e', b'l', b'l', b'o'];
+    assert_eq!(inflate(&stored, 5).unwrap().0, b"hello");
+    assert_eq!(inflate(&stored, 4), Err(InflateError::TooLarge));
+}
@@ -0,0 +1,97 @@
+use ray_tracer::{
+    intersection::Intersections,
+    ray::Ray,
+    shape::{Cone, Shape},
+    tuple::{Point, Vector},
+};
+
+fn local_hits(shape: &Cone, origin: Point, direction: Vector) -> Vec<f32> {
+    let r = Ray::new(origin, direction.normalize());
+    let mut xs = Intersections::new();
+    shape.local_intersect(&r, &mut xs);
+    xs.iter().map(|i| i.t).collect()
+}
+
+#[test]
+fn ray_strikes_cone() {
+    let shape = Cone::new();
+    let cases = [
+        (Point::new(0., 0., -5.), Vector::new(0., 0., 1.), 5., 5.),
+        (
+            Point::new(0., 0., -5.),
+            Vector::new(1., 1., 1.),
+            8.66025,
+            8.66025,
+        ),
+        (
+            Point::new(1., 1., -5.),
+            Vector::new(-0.5, -1., 1.),
+            4.55006,
+            49.44994,
+        ),
+    ];
+
+    for (origin, direction, t0, t1) in cases {
+        let xs = local_hits(&shape, origin, direction);
+        assert_eq!(xs.len(), 2);
+        assert!((xs[0] - t0).abs() < 1e-3);
+        assert!((xs[1] - t1).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn ray_parallel_to_one_half() {
+    let shape = Cone::new();
+    let xs = local_hits(&shape, Point::new(0., 0., -1.), Vector::new(0., 1., 1.));
+    assert_eq!(xs.len(), 1);
+    assert!((xs[0] - 0.35355).abs() < 1e-4);
+}
+
+#[test]
+fn intersect_cone_caps() {
+    let shape = Cone::new().truncated(-0.5, 0.5).closed();
+    let cases = [
+        (Point::new(0., 0., -5.), Vector::new(0., 1., 0.), 0),
+        (Point::new(0., 0., -0.25), Vector::new(0., 1., 1.), 2),
+        (Point::new(0., 0., -0.25), Vector::new(0., 1., 0.), 4),
+    ];
+
+    for (origin, direction, count) in cases {
+        assert_eq!(local_hits(&shape, origin, direction).len(), count);
+    }
+}
+
+#[test]
+fn normal_on_cone() {
+    let shape = Cone::new();
+    let cases = [
+        (Point::new(0., 0., 0.), Vector::new(0., 0., 0.)),
+        (Point::new(1., 1., 1.), Vector::new(1., -(2_f32.sqrt()), 1.)),
+        (Point::new(-1., -1., 0.), Vector::new(-1., 1., 0.)),
+    ];
+
+    for (point, normal) in cases {
+        assert_eq!(shape.local_normal_at(point), normal);
+    }
+}
+
+#[test]
+fn normal_on_cone_caps() {
+    let shape = Cone::new().truncated(-1., 2.).closed();
+    assert_eq!(
+        shape.local_normal_at(Point::new(0.5, 2., 0.5)),
+        Vector::new(0., 1., 0.)
+    );
+    assert_eq!(
+        shape.local_normal_at(Point::new(0.5, -1., 0.)),
+        Vector::new(0., -1., 0.)
+    );
+}
+
+#[test]
+fn untruncated_ends_have_no_caps() {
+    // Only the two halves of the cone itself are hit.
+    let shape = Cone::new().closed();
+    let hits = local_hits(&shape, Point::new(0., 0., -0.25), Vector::new(0., 1., 0.));
+    assert_eq!(hits, [-0.25, 0.25]);
+}
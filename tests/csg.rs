@@ -0,0 +1,228 @@
+use std::ptr;
+
+use ray_tracer::{
+    intersection::{Intersection, Intersections},
+    matrix::Matrix4,
+    ray::Ray,
+    shape::{Csg, CsgOperation, Cube, Group, Shape, Sphere},
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn create_csg() {
+    let c = Csg::new(CsgOperation::Union, Sphere::new(), Cube::new())
+        .with_transform(Matrix4::translation(1., 0., 0.));
+    assert_eq!(c.operation(), CsgOperation::Union);
+    assert_eq!(
+        *c.left().data().parent_transform(),
+        Matrix4::translation(1., 0., 0.)
+    );
+    assert_eq!(
+        *c.right().data().parent_transform(),
+        Matrix4::translation(1., 0., 0.)
+    );
+}
+
+#[test]
+fn operation_rules() {
+    use CsgOperation::*;
+
+    #[rustfmt::skip]
+    let cases = [
+        (Union, true, true, true, false),
+        (Union, true, true, false, true),
+        (Union, true, false, true, false),
+        (Union, true, false, false, true),
+        (Union, false, true, true, false),
+        (Union, false, true, false, false),
+        (Union, false, false, true, true),
+        (Union, false, false, false, true),
+        (Intersection, true, true, true, true),
+        (Intersection, true, true, false, false),
+        (Intersection, true, false, true, true),
+        (Intersection, true, false, false, false),
+        (Intersection, false, true, true, true),
+        (Intersection, false, true, false, true),
+        (Intersection, false, false, true, false),
+        (Intersection, false, false, false, false),
+        (Difference, true, true, true, false),
+        (Difference, true, true, false, true),
+        (Difference, true, false, true, false),
+        (Difference, true, false, false, true),
+        (Difference, false, true, true, true),
+        (Difference, false, true, false, true),
+        (Difference, false, false, true, false),
+        (Difference, false, false, false, false),
+    ];
+
+    for (op, lhit, inl, inr, allowed) in cases {
+        assert_eq!(
+            op.intersection_allowed(lhit, inl, inr),
+            allowed,
+            "{op:?} lhit={lhit} inl={inl} inr={inr}"
+        );
+    }
+}
+
+#[test]
+fn filter_intersections() {
+    let cases = [
+        (CsgOperation::Union, [0, 3]),
+        (CsgOperation::Intersection, [1, 2]),
+        (CsgOperation::Difference, [0, 1]),
+    ];
+
+    for (op, [x0, x1]) in cases {
+        let c = Csg::new(op, Sphere::new(), Cube::new());
+        let ts = [1., 2., 3., 4.];
+        let objects = [c.left(), c.right(), c.left(), c.right()];
+        let mut xs: Intersections = ts
+            .into_iter()
+            .zip(objects)
+            .map(|(t, object)| Intersection::new(t, object))
+            .collect();
+
+        c.filter_intersections(&mut xs);
+        assert_eq!(xs.len(), 2, "{op:?}");
+        assert_eq!(xs[0], Intersection::new(ts[x0], objects[x0]));
+        assert_eq!(xs[1], Intersection::new(ts[x1], objects[x1]));
+    }
+}
+
+#[test]
+fn filter_keeps_unrelated_intersections() {
+    let c = Csg::new(CsgOperation::Intersection, Sphere::new(), Cube::new());
+    let other = Sphere::new();
+    let mut xs: Intersections = [
+        Intersection::new(0.5, &other),
+        Intersection::new(1., c.left()),
+        Intersection::new(2., c.right()),
+        Intersection::new(2.5, &other),
+        Intersection::new(3., c.left()),
+        Intersection::new(4., c.right()),
+    ]
+    .into_iter()
+    .collect();
+
+    c.filter_intersections(&mut xs);
+    let ts: Vec<f32> = xs.iter().map(|i| i.t).collect();
+    assert_eq!(ts, [0.5, 2., 2.5, 3.]);
+}
+
+#[test]
+fn ray_misses_csg() {
+    let c = Csg::new(CsgOperation::Union, Sphere::new(), Cube::new());
+    let r = Ray::new(Point::new(0., 2., -5.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    c.intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn ray_hits_csg() {
+    let c = Csg::new(
+        CsgOperation::Union,
+        Sphere::new(),
+        Sphere::new().with_transform(Matrix4::translation(0., 0., 0.5)),
+    );
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    c.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0], Intersection::new(4., c.left()));
+    assert_eq!(xs[1], Intersection::new(6.5, c.right()));
+}
+
+#[test]
+fn includes_descendants() {
+    let g = Group::new().with_child(Sphere::new());
+    let c = Csg::new(CsgOperation::Union, g, Cube::new());
+    let sphere = c.left().children()[0].as_ref();
+    assert!(c.includes(sphere));
+    assert!(c.left().includes(sphere));
+    assert!(!c.right().includes(sphere));
+    assert!(!c.left().includes(&Sphere::new()));
+}
+
+#[test]
+fn difference_with_group_child() {
+    // A box with two holes drilled through it along z, the holes being
+    // spheres collected in a group.
+    let holes = Group::new()
+        .with_child(
+            Sphere::new().with_transform(Matrix4::scaling(0.25, 0.25, 2.).translate(-0.5, 0., 0.)),
+        )
+        .with_child(
+            Sphere::new().with_transform(Matrix4::scaling(0.25, 0.25, 2.).translate(0.5, 0., 0.)),
+        );
+    let c = Csg::new(CsgOperation::Difference, Cube::new(), holes);
+    let mut xs = Intersections::new();
+
+    let r = Ray::new(Point::new(-0.5, 0., -5.), Vector::new(0., 0., 1.));
+    c.intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    c.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert!(xs.iter().all(|i| ptr::addr_eq(i.object, c.left())));
+}
+
+#[test]
+fn transformed_csg() {
+    let c = Csg::new(CsgOperation::Intersection, Sphere::new(), Cube::new())
+        .with_transform(Matrix4::translation(0., 0., 1.));
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    c.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 5.);
+    assert_eq!(xs[1].t, 7.);
+}
+
+#[test]
+fn intersect_leaves_earlier_hits_in_shared_buffer() {
+    let c = Csg::new(
+        CsgOperation::Intersection,
+        Sphere::new(),
+        Cube::new().with_transform(Matrix4::scaling(0.5, 0.5, 0.5)),
+    );
+    let other = Sphere::new();
+    let mut xs: Intersections = [Intersection::new(1., &other), Intersection::new(8., &other)]
+        .into_iter()
+        .collect();
+
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    c.intersect(&r, &mut xs);
+    let ts: Vec<f32> = xs.iter().map(|i| i.t).collect();
+    assert_eq!(ts, [1., 4.5, 5.5, 8.]);
+    assert_eq!(xs[1], Intersection::new(4.5, c.right()));
+    assert_eq!(xs[2], Intersection::new(5.5, c.right()));
+}
+
+#[test]
+fn reuse_buffer_across_csg_rays() {
+    let inner = Csg::new(CsgOperation::Union, Sphere::new(), Cube::new());
+    let c = Csg::new(
+        CsgOperation::Difference,
+        inner,
+        Group::new().with_child(Sphere::new().with_transform(Matrix4::scaling(0.5, 0.5, 2.))),
+    );
+    let mut xs = Intersections::new();
+    let rays = [
+        Ray::new(Point::new(0., 0.75, -5.), Vector::new(0., 0., 1.)),
+        Ray::new(Point::new(0.75, 0., -5.), Vector::new(0., 0., 1.)),
+        Ray::new(Point::new(-5., 0.1, 0.), Vector::new(1., 0., 0.)),
+    ];
+
+    let mut capacity = None;
+    for _ in 0..4 {
+        for r in &rays {
+            xs.clear();
+            c.intersect(r, &mut xs);
+            assert!(!xs.is_empty());
+        }
+        // The first pass over the rays sizes the buffer; later ones reuse it.
+        assert_eq!(*capacity.get_or_insert(xs.capacity()), xs.capacity());
+    }
+}
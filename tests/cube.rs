@@ -0,0 +1,68 @@
+use ray_tracer::{
+    intersection::Intersections,
+    ray::Ray,
+    shape::{Cube, Shape},
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn ray_intersects_cube() {
+    let c = Cube::new();
+    let cases = [
+        (Point::new(5., 0.5, 0.), Vector::new(-1., 0., 0.), 4., 6.),
+        (Point::new(-5., 0.5, 0.), Vector::new(1., 0., 0.), 4., 6.),
+        (Point::new(0.5, 5., 0.), Vector::new(0., -1., 0.), 4., 6.),
+        (Point::new(0.5, -5., 0.), Vector::new(0., 1., 0.), 4., 6.),
+        (Point::new(0.5, 0., 5.), Vector::new(0., 0., -1.), 4., 6.),
+        (Point::new(0.5, 0., -5.), Vector::new(0., 0., 1.), 4., 6.),
+        (Point::new(0., 0.5, 0.), Vector::new(0., 0., 1.), -1., 1.),
+    ];
+
+    for (origin, direction, t1, t2) in cases {
+        let r = Ray::new(origin, direction);
+        let mut xs = Intersections::new();
+        c.local_intersect(&r, &mut xs);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, t1);
+        assert_eq!(xs[1].t, t2);
+    }
+}
+
+#[test]
+fn ray_misses_cube() {
+    let c = Cube::new();
+    let cases = [
+        (Point::new(-2., 0., 0.), Vector::new(0.2673, 0.5345, 0.8018)),
+        (Point::new(0., -2., 0.), Vector::new(0.8018, 0.2673, 0.5345)),
+        (Point::new(0., 0., -2.), Vector::new(0.5345, 0.8018, 0.2673)),
+        (Point::new(2., 0., 2.), Vector::new(0., 0., -1.)),
+        (Point::new(0., 2., 2.), Vector::new(0., -1., 0.)),
+        (Point::new(2., 2., 0.), Vector::new(-1., 0., 0.)),
+    ];
+
+    for (origin, direction) in cases {
+        let r = Ray::new(origin, direction);
+        let mut xs = Intersections::new();
+        c.local_intersect(&r, &mut xs);
+        assert!(xs.is_empty());
+    }
+}
+
+#[test]
+fn normal_on_cube() {
+    let c = Cube::new();
+    let cases = [
+        (Point::new(1., 0.5, -0.8), Vector::new(1., 0., 0.)),
+        (Point::new(-1., -0.2, 0.9), Vector::new(-1., 0., 0.)),
+        (Point::new(-0.4, 1., -0.1), Vector::new(0., 1., 0.)),
+        (Point::new(0.3, -1., -0.7), Vector::new(0., -1., 0.)),
+        (Point::new(-0.6, 0.3, 1.), Vector::new(0., 0., 1.)),
+        (Point::new(0.4, 0.4, -1.), Vector::new(0., 0., -1.)),
+        (Point::new(1., 1., 1.), Vector::new(1., 0., 0.)),
+        (Point::new(-1., -1., -1.), Vector::new(-1., 0., 0.)),
+    ];
+
+    for (point, normal) in cases {
+        assert_eq!(c.local_normal_at(point), normal);
+    }
+}
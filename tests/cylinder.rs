@@ -0,0 +1,136 @@
+use ray_tracer::{
+    intersection::Intersections,
+    ray::Ray,
+    shape::{Cylinder, Shape},
+    tuple::{Point, Vector},
+};
+
+fn local_hits(shape: &Cylinder, origin: Point, direction: Vector) -> Vec<f32> {
+    let r = Ray::new(origin, direction.normalize());
+    let mut xs = Intersections::new();
+    shape.local_intersect(&r, &mut xs);
+    xs.iter().map(|i| i.t).collect()
+}
+
+#[test]
+fn ray_misses_cylinder() {
+    let cyl = Cylinder::new();
+    let cases = [
+        (Point::new(1., 0., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0., 0., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0., 0., -5.), Vector::new(1., 1., 1.)),
+    ];
+
+    for (origin, direction) in cases {
+        assert!(local_hits(&cyl, origin, direction).is_empty());
+    }
+}
+
+#[test]
+fn ray_strikes_cylinder() {
+    let cyl = Cylinder::new();
+    let cases = [
+        (Point::new(1., 0., -5.), Vector::new(0., 0., 1.), 5., 5.),
+        (Point::new(0., 0., -5.), Vector::new(0., 0., 1.), 4., 6.),
+        (
+            Point::new(0.5, 0., -5.),
+            Vector::new(0.1, 1., 1.),
+            6.80798,
+            7.08872,
+        ),
+    ];
+
+    for (origin, direction, t0, t1) in cases {
+        let xs = local_hits(&cyl, origin, direction);
+        assert_eq!(xs.len(), 2);
+        assert!((xs[0] - t0).abs() < 1e-4);
+        assert!((xs[1] - t1).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn normal_on_cylinder() {
+    let cyl = Cylinder::new();
+    let cases = [
+        (Point::new(1., 0., 0.), Vector::new(1., 0., 0.)),
+        (Point::new(0., 5., -1.), Vector::new(0., 0., -1.)),
+        (Point::new(0., -2., 1.), Vector::new(0., 0., 1.)),
+        (Point::new(-1., 1., 0.), Vector::new(-1., 0., 0.)),
+    ];
+
+    for (point, normal) in cases {
+        assert_eq!(cyl.local_normal_at(point), normal);
+    }
+}
+
+#[test]
+fn default_cylinder_bounds() {
+    let cyl = Cylinder::new();
+    assert_eq!(cyl.minimum, f32::NEG_INFINITY);
+    assert_eq!(cyl.maximum, f32::INFINITY);
+    assert!(!cyl.closed);
+}
+
+#[test]
+fn intersect_truncated_cylinder() {
+    let cyl = Cylinder::new().truncated(1., 2.);
+    let cases = [
+        (Point::new(0., 1.5, 0.), Vector::new(0.1, 1., 0.), 0),
+        (Point::new(0., 3., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 0., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 2., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 1., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 1.5, -2.), Vector::new(0., 0., 1.), 2),
+    ];
+
+    for (origin, direction, count) in cases {
+        assert_eq!(local_hits(&cyl, origin, direction).len(), count);
+    }
+}
+
+#[test]
+fn intersect_caps_of_closed_cylinder() {
+    let cyl = Cylinder::new().truncated(1., 2.).closed();
+    let cases = [
+        (Point::new(0., 3., 0.), Vector::new(0., -1., 0.), 2),
+        (Point::new(0., 3., -2.), Vector::new(0., -1., 2.), 2),
+        (Point::new(0., 4., -2.), Vector::new(0., -1., 1.), 2),
+        (Point::new(0., 0., -2.), Vector::new(0., 1., 2.), 2),
+        (Point::new(0., -1., -2.), Vector::new(0., 1., 1.), 2),
+    ];
+
+    for (origin, direction, count) in cases {
+        assert_eq!(local_hits(&cyl, origin, direction).len(), count);
+    }
+}
+
+#[test]
+fn normal_on_cylinder_caps() {
+    let cyl = Cylinder::new().truncated(1., 2.).closed();
+    let cases = [
+        (Point::new(0., 1., 0.), Vector::new(0., -1., 0.)),
+        (Point::new(0.5, 1., 0.), Vector::new(0., -1., 0.)),
+        (Point::new(0., 1., 0.5), Vector::new(0., -1., 0.)),
+        (Point::new(0., 2., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0.5, 2., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0., 2., 0.5), Vector::new(0., 1., 0.)),
+    ];
+
+    for (point, normal) in cases {
+        assert_eq!(cyl.local_normal_at(point), normal);
+    }
+}
+
+#[test]
+fn untruncated_ends_have_no_caps() {
+    let cyl = Cylinder::new().closed();
+    let down = Vector::new(0., -1., 0.);
+    assert!(local_hits(&cyl, Point::new(0., 3., 0.), down).is_empty());
+    let hits = local_hits(&cyl, Point::new(0., 3., -2.), Vector::new(0., -1., 2.));
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|t| t.is_finite()));
+
+    // Only the truncated end is capped.
+    let cyl = Cylinder::new().truncated(f32::NEG_INFINITY, 1.).closed();
+    assert_eq!(local_hits(&cyl, Point::new(0., 3., 0.), down), [2.]);
+}
@@ -0,0 +1,159 @@
+use std::{
+    f32::consts::{FRAC_PI_2, FRAC_PI_3, PI},
+    ptr,
+};
+
+use ray_tracer::{
+    intersection::Intersections,
+    matrix::Matrix4,
+    ray::Ray,
+    shape::{Cylinder, Group, Shape, Sphere},
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn create_group() {
+    let g = Group::new();
+    assert_eq!(*g.transform(), Matrix4::IDENTITY);
+    assert!(g.is_empty());
+}
+
+#[test]
+fn add_child_to_group() {
+    let g = Group::new()
+        .with_transform(Matrix4::translation(1., 0., 0.))
+        .with_child(Sphere::new());
+    assert_eq!(g.children().len(), 1);
+    assert_eq!(
+        *g.children()[0].data().parent_transform(),
+        Matrix4::translation(1., 0., 0.)
+    );
+}
+
+#[test]
+fn intersect_empty_group() {
+    let g = Group::new();
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    g.intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn intersect_nonempty_group() {
+    let g = Group::new()
+        .with_child(Sphere::new())
+        .with_child(Sphere::new().with_transform(Matrix4::translation(0., 0., -3.)))
+        .with_child(Sphere::new().with_transform(Matrix4::translation(5., 0., 0.)));
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    g.intersect(&r, &mut xs);
+
+    let [s1, s2, _] = g.children() else {
+        unreachable!()
+    };
+    assert_eq!(xs.len(), 4);
+    assert!(ptr::addr_eq(xs[0].object, s2.as_ref()));
+    assert!(ptr::addr_eq(xs[1].object, s2.as_ref()));
+    assert!(ptr::addr_eq(xs[2].object, s1.as_ref()));
+    assert!(ptr::addr_eq(xs[3].object, s1.as_ref()));
+}
+
+#[test]
+fn intersect_transformed_group() {
+    let g = Group::new()
+        .with_transform(Matrix4::scaling(2., 2., 2.))
+        .with_child(Sphere::new().with_transform(Matrix4::translation(5., 0., 0.)));
+    let r = Ray::new(Point::new(10., 0., -10.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    g.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+}
+
+fn nested_groups(inner: Matrix4) -> Group {
+    let g2 = Group::new()
+        .with_transform(Matrix4::scaling(4., 4., 4.))
+        .with_child(Sphere::new().with_transform(Matrix4::translation(5., 0., 0.)));
+    let mut g1 = Group::new().with_child(g2);
+
+    // Transforming the outer group after the hierarchy is built must still
+    // reach the innermost child.
+    g1.set_transform(Matrix4::rotation_y(FRAC_PI_2));
+    g1.children_mut()[0].set_transform(inner);
+    g1
+}
+
+#[test]
+fn world_to_object_through_parents() {
+    let g1 = nested_groups(Matrix4::scaling(2., 2., 2.));
+    let s = &g1.children()[0].children()[0];
+    assert_eq!(
+        s.world_to_object(Point::new(-2., 0., -10.)),
+        Point::new(0., 0., -1.)
+    );
+}
+
+#[test]
+fn normal_to_world_through_parents() {
+    let g1 = nested_groups(Matrix4::scaling(1., 2., 3.));
+    let s = &g1.children()[0].children()[0];
+    let v = 3_f32.sqrt() / 3.;
+    assert_eq!(
+        s.normal_to_world(Vector::new(v, v, v)),
+        Vector::new(0.28571, 0.42857, -0.85714)
+    );
+}
+
+#[test]
+fn normal_on_child_object() {
+    let g1 = nested_groups(Matrix4::scaling(1., 2., 3.));
+    let s = &g1.children()[0].children()[0];
+    assert_eq!(
+        s.normal_at(Point::new(1.7321, 1.1547, -5.5774)),
+        Vector::new(0.28570, 0.42854, -0.85716)
+    );
+}
+
+fn hexagon_corner() -> Sphere {
+    Sphere::new().with_transform(Matrix4::scaling(0.25, 0.25, 0.25).translate(0., 0., -1.))
+}
+
+fn hexagon_edge() -> Cylinder {
+    Cylinder::new().truncated(0., 1.).with_transform(
+        Matrix4::scaling(0.25, 1., 0.25)
+            .rotate_z(-FRAC_PI_2)
+            .rotate_y(-PI / 6.)
+            .translate(0., 0., -1.),
+    )
+}
+
+fn hexagon() -> Group {
+    (0..6).fold(Group::new(), |hex, n| {
+        let side = Group::new()
+            .with_child(hexagon_corner())
+            .with_child(hexagon_edge())
+            .with_transform(Matrix4::rotation_y(n as f32 * FRAC_PI_3));
+        hex.with_child(side)
+    })
+}
+
+#[test]
+fn moving_parent_moves_children() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let mut hex = hexagon();
+
+    let mut xs = Intersections::new();
+    hex.intersect(&r, &mut xs);
+    let near = xs.hit().unwrap().t;
+    assert!((near - 3.75).abs() < 1e-4);
+
+    hex.set_transform(Matrix4::translation(0., 0., 2.));
+    let mut xs = Intersections::new();
+    hex.intersect(&r, &mut xs);
+    assert!((xs.hit().unwrap().t - (near + 2.)).abs() < 1e-4);
+
+    hex.set_transform(Matrix4::translation(0., 10., 0.));
+    let mut xs = Intersections::new();
+    hex.intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
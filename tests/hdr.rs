@@ -0,0 +1,143 @@
+use ray_tracer::{
+    canvas::{Canvas, ImageError},
+    color::Color,
+};
+
+fn bright_canvas(width: u32, height: u32) -> Canvas {
+    let mut c = Canvas::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            // Long flat stretches mixed with values far beyond 1.
+            let v = if x < width / 2 { 4. } else { x as f32 * 7.5 };
+            c.write_pixel(x, y, Color::new(v, y as f32 * 0.01, 1000. / (x + 1) as f32));
+        }
+    }
+    c
+}
+
+fn write(c: &Canvas) -> Vec<u8> {
+    let mut out = Vec::new();
+    c.write_hdr(&mut out).unwrap();
+    out
+}
+
+fn assert_close(read: &Canvas, original: &Canvas) {
+    assert_eq!((read.width, read.height), (original.width, original.height));
+    for (a, b) in read.pixels.iter().zip(&original.pixels) {
+        assert_eq!(a.to_rgbe(), b.to_rgbe(), "{a:?} != {b:?}");
+    }
+}
+
+#[test]
+fn written_header() {
+    let data = write(&bright_canvas(3, 2));
+    let header = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 2 +X 3\n";
+    assert!(data.starts_with(header));
+    // Too narrow for run-length encoding, so the pixels are stored flat.
+    assert_eq!(data.len(), header.len() + 3 * 2 * 4);
+    assert_eq!(
+        &data[header.len()..header.len() + 4],
+        Color::new(4., 0., 1000.).to_rgbe()
+    );
+}
+
+#[test]
+fn wide_scanlines_are_run_length_encoded() {
+    let c = Canvas::new(300, 1);
+    let data = write(&c);
+    let header_len = data.len() - 4 - 4 * 6;
+    // Each channel of 300 black pixels takes runs of 127, 127 and 46.
+    assert_eq!(&data[header_len..header_len + 4], [2, 2, 1, 44]);
+    assert_eq!(
+        &data[header_len + 4..header_len + 10],
+        [255, 0, 255, 0, 174, 0]
+    );
+}
+
+#[test]
+fn round_trip() {
+    for (width, height) in [(1, 1), (7, 3), (8, 2), (40, 5), (300, 2)] {
+        let c = bright_canvas(width, height);
+        let read = Canvas::read_hdr(write(&c).as_slice()).unwrap();
+        assert_close(&read, &c);
+    }
+}
+
+#[test]
+fn round_trip_literal_stretches_longer_than_128() {
+    let mut c = Canvas::new(400, 1);
+    for x in 0..400 {
+        c.write_pixel(x, 0, Color::new(x as f32 / 400., (x % 2) as f32, 0.5));
+    }
+    let read = Canvas::read_hdr(write(&c).as_slice()).unwrap();
+    assert_close(&read, &c);
+}
+
+#[test]
+fn empty_canvas_cannot_be_written() {
+    assert!(Canvas::new(4, 0).write_hdr(Vec::new()).is_err());
+}
+
+#[test]
+fn read_header_variants() {
+    let mut data =
+        b"#?RGBE\n# made by hand\nEXPOSURE=2\nGAMMA=1.0\nEXPOSURE= 0.5\nEXPOSURE=4\n\n-Y 1 +X 2\n"
+            .to_vec();
+    data.extend([128, 64, 32, 129, 0, 0, 0, 0]);
+    let c = Canvas::read_hdr(data.as_slice()).unwrap();
+    assert_eq!(c.pixels, [Color::new(0.25, 0.125, 0.0625), Color::BLACK]);
+}
+
+#[test]
+fn malformed_images() {
+    let mut wrong_width = b"#?RADIANCE\n\n-Y 1 +X 8\n".to_vec();
+    wrong_width.extend([2, 2, 0, 9]);
+    let mut overlong_run = b"#?RADIANCE\n\n-Y 1 +X 8\n".to_vec();
+    overlong_run.extend([2, 2, 0, 8, 137, 0]);
+
+    type Check = fn(&ImageError) -> bool;
+    let cases: [(Vec<u8>, Check); 10] = [
+        (b"#".to_vec(), |e| matches!(e, ImageError::Truncated)),
+        (b"P6\n1 1\n255\n".to_vec(), |e| {
+            matches!(e, ImageError::UnknownFormat)
+        }),
+        (b"#?RADIANCE\nFORMAT=32-bit_rle_xyze\n\n".to_vec(), |e| {
+            matches!(e, ImageError::Unsupported(_))
+        }),
+        (b"#?RADIANCE\nFORMAT=jpeg\n\n".to_vec(), |e| {
+            matches!(e, ImageError::InvalidHeader("format"))
+        }),
+        (b"#?RADIANCE\nEXPOSURE=-1\n\n".to_vec(), |e| {
+            matches!(e, ImageError::InvalidHeader("exposure"))
+        }),
+        (b"#?RADIANCE\n\n+X 2 -Y 1\n".to_vec(), |e| {
+            matches!(e, ImageError::Unsupported(_))
+        }),
+        (b"#?RADIANCE\n\n-Y 0 +X 1\n".to_vec(), |e| {
+            matches!(
+                e,
+                ImageError::InvalidDimensions {
+                    width: 1,
+                    height: 0
+                }
+            )
+        }),
+        (b"#?RADIANCE\n\n-Y 2 +X 1\n\0\0\0\0".to_vec(), |e| {
+            matches!(e, ImageError::Truncated)
+        }),
+        (wrong_width, |e| matches!(e, ImageError::Malformed(_))),
+        (overlong_run, |e| matches!(e, ImageError::Malformed(_))),
+    ];
+
+    for (i, (data, expected)) in cases.into_iter().enumerate() {
+        let err = Canvas::read_hdr(data.as_slice()).unwrap_err();
+        assert!(expected(&err), "case {i}: {err:?}");
+    }
+}
+
+#[test]
+fn huge_dimensions_fail_before_allocating() {
+    let err =
+        Canvas::read_hdr(b"#?RADIANCE\n\n-Y 60000 +X 60000\n\0\0\0\0".as_slice()).unwrap_err();
+    assert!(matches!(err, ImageError::Truncated));
+}
@@ -0,0 +1,241 @@
+mod common;
+
+use std::ptr;
+
+use common::glass_sphere;
+use ray_tracer::{
+    EPSILON,
+    intersection::{Intersection, Intersections},
+    matrix::Matrix4,
+    ray::Ray,
+    shape::{Plane, Shape, Sphere, Triangle},
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn intersection_encapsulates_t_and_object() {
+    let s = Sphere::new();
+    let i = Intersection::new(3.5, &s);
+    assert_eq!(i.t, 3.5);
+    assert!(ptr::addr_eq(i.object, &s));
+}
+
+#[test]
+fn aggregate_intersections() {
+    let s = Sphere::new();
+    let xs = Intersections::from_iter([Intersection::new(2., &s), Intersection::new(1., &s)]);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 1.);
+    assert_eq!(xs[1].t, 2.);
+}
+
+#[test]
+fn intersect_sets_object() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let s = Sphere::new();
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert!(ptr::addr_eq(xs[0].object, &s));
+    assert!(ptr::addr_eq(xs[1].object, &s));
+}
+
+#[test]
+fn hit_all_positive() {
+    let s = Sphere::new();
+    let i1 = Intersection::new(1., &s);
+    let i2 = Intersection::new(2., &s);
+    let xs = Intersections::from_iter([i2, i1]);
+    assert_eq!(xs.hit(), Some(&i1));
+}
+
+#[test]
+fn hit_some_negative() {
+    let s = Sphere::new();
+    let i1 = Intersection::new(-1., &s);
+    let i2 = Intersection::new(1., &s);
+    let xs = Intersections::from_iter([i2, i1]);
+    assert_eq!(xs.hit(), Some(&i2));
+}
+
+#[test]
+fn hit_all_negative() {
+    let s = Sphere::new();
+    let i1 = Intersection::new(-2., &s);
+    let i2 = Intersection::new(-1., &s);
+    let xs = Intersections::from_iter([i2, i1]);
+    assert_eq!(xs.hit(), None);
+}
+
+#[test]
+fn hit_is_lowest_nonnegative() {
+    let s = Sphere::new();
+    let i1 = Intersection::new(5., &s);
+    let i2 = Intersection::new(7., &s);
+    let i3 = Intersection::new(-3., &s);
+    let i4 = Intersection::new(2., &s);
+    let xs = Intersections::from_iter([i1, i2, i3, i4]);
+    assert_eq!(xs.hit(), Some(&i4));
+}
+
+#[test]
+fn reuse_buffer_across_shapes_and_rays() {
+    let s1 = Sphere::new();
+    let s2 = Sphere::new();
+    let mut xs = Intersections::with_capacity(8);
+
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    s1.intersect(&r, &mut xs);
+    s2.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 4);
+    assert!(xs.windows(2).all(|w| w[0].t <= w[1].t));
+
+    let capacity = xs.capacity();
+    xs.clear();
+    let r = Ray::new(Point::new(0., 0., 5.), Vector::new(0., 0., 1.));
+    s1.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs.hit(), None);
+    assert_eq!(xs.capacity(), capacity);
+}
+
+#[test]
+fn precompute_intersection_state() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let shape = Sphere::new();
+    let i = Intersection::new(4., &shape);
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(comps.t, i.t);
+    assert!(ptr::addr_eq(comps.object, i.object));
+    assert_eq!(comps.point, Point::new(0., 0., -1.));
+    assert_eq!(comps.eyev, Vector::new(0., 0., -1.));
+    assert_eq!(comps.normalv, Vector::new(0., 0., -1.));
+}
+
+#[test]
+fn hit_on_outside() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let shape = Sphere::new();
+    let i = Intersection::new(4., &shape);
+    let comps = i.prepare_computations(&r, &[i]);
+    assert!(!comps.inside);
+}
+
+#[test]
+fn hit_on_inside() {
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    let shape = Sphere::new();
+    let i = Intersection::new(1., &shape);
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(comps.point, Point::new(0., 0., 1.));
+    assert_eq!(comps.eyev, Vector::new(0., 0., -1.));
+    assert!(comps.inside);
+    assert_eq!(comps.normalv, Vector::new(0., 0., -1.));
+}
+
+#[test]
+fn hit_offsets_point() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let shape = Sphere::new().with_transform(Matrix4::translation(0., 0., 1.));
+    let i = Intersection::new(5., &shape);
+    let comps = i.prepare_computations(&r, &[i]);
+    assert!(comps.over_point.z < -EPSILON / 2.);
+    assert!(comps.point.z > comps.over_point.z);
+}
+
+#[test]
+fn precompute_reflection_vector() {
+    let shape = Plane::new();
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 1., -1.), Vector::new(0., -v, v));
+    let i = Intersection::new(2_f32.sqrt(), &shape);
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(comps.reflectv, Vector::new(0., v, v));
+}
+
+#[test]
+fn n1_and_n2_at_various_intersections() {
+    let mut a = glass_sphere().with_transform(Matrix4::scaling(2., 2., 2.));
+    a.material_mut().refractive_index = 1.5;
+    let mut b = glass_sphere().with_transform(Matrix4::translation(0., 0., -0.25));
+    b.material_mut().refractive_index = 2.;
+    let mut c = glass_sphere().with_transform(Matrix4::translation(0., 0., 0.25));
+    c.material_mut().refractive_index = 2.5;
+
+    let r = Ray::new(Point::new(0., 0., -4.), Vector::new(0., 0., 1.));
+    let xs = Intersections::from_iter([
+        Intersection::new(2., &a),
+        Intersection::new(2.75, &b),
+        Intersection::new(3.25, &c),
+        Intersection::new(4.75, &b),
+        Intersection::new(5.25, &c),
+        Intersection::new(6., &a),
+    ]);
+    let expected = [
+        (1.0, 1.5),
+        (1.5, 2.0),
+        (2.0, 2.5),
+        (2.5, 2.5),
+        (2.5, 1.5),
+        (1.5, 1.0),
+    ];
+
+    for (i, (n1, n2)) in xs.iter().zip(expected) {
+        let comps = i.prepare_computations(&r, &xs);
+        assert_eq!((comps.n1, comps.n2), (n1, n2));
+    }
+}
+
+#[test]
+fn under_point_below_surface() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let shape = glass_sphere().with_transform(Matrix4::translation(0., 0., 1.));
+    let i = Intersection::new(5., &shape);
+    let comps = i.prepare_computations(&r, &[i]);
+    assert!(comps.under_point.z > EPSILON / 2.);
+    assert!(comps.point.z < comps.under_point.z);
+}
+
+#[test]
+fn schlick_under_total_internal_reflection() {
+    let shape = glass_sphere();
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 0., v), Vector::new(0., 1., 0.));
+    let xs =
+        Intersections::from_iter([Intersection::new(-v, &shape), Intersection::new(v, &shape)]);
+    let comps = xs[1].prepare_computations(&r, &xs);
+    assert_eq!(comps.schlick(), 1.);
+}
+
+#[test]
+fn schlick_with_perpendicular_ray() {
+    let shape = glass_sphere();
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 1., 0.));
+    let xs = Intersections::from_iter([
+        Intersection::new(-1., &shape),
+        Intersection::new(1., &shape),
+    ]);
+    let comps = xs[1].prepare_computations(&r, &xs);
+    assert!((comps.schlick() - 0.04).abs() < EPSILON);
+}
+
+#[test]
+fn schlick_with_small_angle_and_n2_greater() {
+    let shape = glass_sphere();
+    let r = Ray::new(Point::new(0., 0.99, -2.), Vector::new(0., 0., 1.));
+    let xs = Intersections::from_iter([Intersection::new(1.8589, &shape)]);
+    let comps = xs[0].prepare_computations(&r, &xs);
+    assert!((comps.schlick() - 0.48873).abs() < 1e-4);
+}
+
+#[test]
+fn intersection_with_uv() {
+    let s = Triangle::new(
+        Point::new(0., 1., 0.),
+        Point::new(-1., 0., 0.),
+        Point::new(1., 0., 0.),
+    );
+    let i = Intersection::with_uv(3.5, &s, 0.2, 0.4);
+    assert_eq!(i.u, 0.2);
+    assert_eq!(i.v, 0.4);
+}
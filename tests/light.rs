@@ -0,0 +1,10 @@
+use ray_tracer::{color::Color, light::PointLight, tuple::Point};
+
+#[test]
+fn point_light() {
+    let intensity = Color::new(1., 1., 1.);
+    let position = Point::new(0., 0., 0.);
+    let light = PointLight::new(position, intensity);
+    assert_eq!(light.position, position);
+    assert_eq!(light.intensity, intensity);
+}
@@ -0,0 +1,137 @@
+use ray_tracer::{
+    color::Color,
+    light::PointLight,
+    material::{Material, lighting},
+    pattern::Stripe,
+    shape::Sphere,
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn default_material() {
+    let m = Material::default();
+    assert_eq!(m.color, Color::new(1., 1., 1.));
+    assert_eq!(m.ambient, 0.1);
+    assert_eq!(m.diffuse, 0.9);
+    assert_eq!(m.specular, 0.9);
+    assert_eq!(m.shininess, 200.);
+    assert_eq!(m.reflective, 0.);
+    assert_eq!(m.transparency, 0.);
+    assert_eq!(m.refractive_index, 1.);
+}
+
+#[test]
+fn lighting_eye_between_light_and_surface() {
+    let m = Material::default();
+    let position = Point::new(0., 0., 0.);
+    let eyev = Vector::new(0., 0., -1.);
+    let normalv = Vector::new(0., 0., -1.);
+    let light = PointLight::new(Point::new(0., 0., -10.), Color::new(1., 1., 1.));
+    assert_eq!(
+        lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false),
+        Color::new(1.9, 1.9, 1.9)
+    );
+}
+
+#[test]
+fn lighting_eye_offset_45_degrees() {
+    let m = Material::default();
+    let position = Point::new(0., 0., 0.);
+    let r = 2_f32.sqrt() / 2.;
+    let eyev = Vector::new(0., r, -r);
+    let normalv = Vector::new(0., 0., -1.);
+    let light = PointLight::new(Point::new(0., 0., -10.), Color::new(1., 1., 1.));
+    assert_eq!(
+        lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false),
+        Color::new(1., 1., 1.)
+    );
+}
+
+#[test]
+fn lighting_light_offset_45_degrees() {
+    let m = Material::default();
+    let position = Point::new(0., 0., 0.);
+    let eyev = Vector::new(0., 0., -1.);
+    let normalv = Vector::new(0., 0., -1.);
+    let light = PointLight::new(Point::new(0., 10., -10.), Color::new(1., 1., 1.));
+    assert_eq!(
+        lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false),
+        Color::new(0.736396, 0.736396, 0.736396)
+    );
+}
+
+#[test]
+fn lighting_eye_in_reflection_path() {
+    let m = Material::default();
+    let position = Point::new(0., 0., 0.);
+    let r = 2_f32.sqrt() / 2.;
+    let eyev = Vector::new(0., -r, -r);
+    let normalv = Vector::new(0., 0., -1.);
+    let light = PointLight::new(Point::new(0., 10., -10.), Color::new(1., 1., 1.));
+    assert_eq!(
+        lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false),
+        Color::new(1.63638, 1.63638, 1.63638)
+    );
+}
+
+#[test]
+fn lighting_light_behind_surface() {
+    let m = Material::default();
+    let position = Point::new(0., 0., 0.);
+    let eyev = Vector::new(0., 0., -1.);
+    let normalv = Vector::new(0., 0., -1.);
+    let light = PointLight::new(Point::new(0., 0., 10.), Color::new(1., 1., 1.));
+    assert_eq!(
+        lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false),
+        Color::new(0.1, 0.1, 0.1)
+    );
+}
+
+#[test]
+fn lighting_surface_in_shadow() {
+    let m = Material::default();
+    let position = Point::new(0., 0., 0.);
+    let eyev = Vector::new(0., 0., -1.);
+    let normalv = Vector::new(0., 0., -1.);
+    let light = PointLight::new(Point::new(0., 0., -10.), Color::new(1., 1., 1.));
+    assert_eq!(
+        lighting(&m, &Sphere::new(), &light, position, eyev, normalv, true),
+        Color::new(0.1, 0.1, 0.1)
+    );
+}
+
+#[test]
+fn lighting_with_pattern() {
+    let m = Material {
+        ambient: 1.,
+        diffuse: 0.,
+        specular: 0.,
+        ..Material::default()
+    }
+    .with_pattern(Stripe::new(Color::WHITE, Color::BLACK));
+    let eyev = Vector::new(0., 0., -1.);
+    let normalv = Vector::new(0., 0., -1.);
+    let light = PointLight::new(Point::new(0., 0., -10.), Color::new(1., 1., 1.));
+    let object = Sphere::new();
+
+    let c1 = lighting(
+        &m,
+        &object,
+        &light,
+        Point::new(0.9, 0., 0.),
+        eyev,
+        normalv,
+        false,
+    );
+    let c2 = lighting(
+        &m,
+        &object,
+        &light,
+        Point::new(1.1, 0., 0.),
+        eyev,
+        normalv,
+        false,
+    );
+    assert_eq!(c1, Color::WHITE);
+    assert_eq!(c2, Color::BLACK);
+}
@@ -1,4 +1,7 @@
-use ray_tracer::{matrix::Matrix, tuple::Tuple};
+use ray_tracer::{
+    matrix::{Matrix, Matrix4},
+    tuple::{Point, Tuple, Vector},
+};
 
 #[test]
 fn matrix() {
@@ -210,3 +213,255 @@ fn determinant_large() {
     assert_eq!(m.cofactor(0, 3), 51.);
     assert_eq!(m.determinant(), -4071.);
 }
+
+#[test]
+fn matrix_invertible() {
+    let m = Matrix::from_iter([
+        [6., 4., 4., 4.],
+        [5., 5., 7., 6.],
+        [4., -9., 3., -7.],
+        [9., 1., 7., -6.],
+    ]);
+    assert_eq!(m.determinant(), -2120.);
+    assert!(m.try_inverse().is_some());
+
+    let m = Matrix::from_iter([
+        [-4., 2., -2., -3.],
+        [9., 6., 2., 6.],
+        [0., -5., 1., -5.],
+        [0., 0., 0., 0.],
+    ]);
+    assert_eq!(m.determinant(), 0.);
+    assert!(m.try_inverse().is_none());
+}
+
+#[test]
+fn matrix_inverse() {
+    let m = Matrix::from_iter([
+        [-5., 2., 6., -8.],
+        [1., -5., 1., 8.],
+        [7., 7., -6., -7.],
+        [1., -3., 7., 4.],
+    ]);
+    let inv = m.inverse();
+
+    assert_eq!(m.determinant(), 532.);
+    assert_eq!(m.cofactor(2, 3), -160.);
+    assert_eq!(m.cofactor(3, 2), 105.);
+    assert_eq!(
+        inv,
+        Matrix::from_iter([
+            [116. / 532., 240. / 532., 128. / 532., -24. / 532.],
+            [-430. / 532., -775. / 532., -236. / 532., 277. / 532.],
+            [-42. / 532., -119. / 532., -28. / 532., 105. / 532.],
+            [-278. / 532., -433. / 532., -160. / 532., 163. / 532.],
+        ])
+    );
+}
+
+#[test]
+fn matrix_mul_by_inverse() {
+    let a = Matrix::from_iter([
+        [3., -9., 7., 3.],
+        [3., -8., 2., -9.],
+        [-4., 4., 4., 1.],
+        [-6., 5., -1., 1.],
+    ]);
+    let b = Matrix::from_iter([
+        [8., 2., 2., 2.],
+        [3., -1., 7., 0.],
+        [7., 0., 5., 4.],
+        [6., -2., 0., 5.],
+    ]);
+    let c = a.clone() * b.clone();
+    assert_eq!(c * b.inverse(), a);
+}
+
+#[test]
+fn matrix_inverse_non_4x4() {
+    let m = Matrix::from_iter([[1., 2., 6.], [-5., 8., -4.], [2., 6., 4.]]);
+    assert_eq!(m.clone() * m.inverse(), Matrix::identity(3, 3));
+
+    let m = Matrix::from_iter([
+        [2., 0., 1., 0., 3.],
+        [1., 4., 0., 2., 0.],
+        [0., 1., 3., 0., 1.],
+        [5., 0., 0., 1., 2.],
+        [0., 2., 1., 3., 1.],
+    ]);
+    assert_eq!(m.inverse() * m, Matrix::identity(5, 5));
+
+    let m = Matrix::from_iter([[1., 2.], [2., 4.]]);
+    assert!(m.try_inverse().is_none());
+}
+
+#[test]
+fn matrix_inverse_large_translation() {
+    // A 2D translation by (1000, 1000) in homogeneous coordinates.
+    let m = Matrix::from_iter([[1., 0., 1000.], [0., 1., 1000.], [0., 0., 1.]]);
+    let inverse = m.try_inverse().unwrap();
+    assert_eq!(
+        inverse,
+        Matrix::from_iter([[1., 0., -1000.], [0., 1., -1000.], [0., 0., 1.]])
+    );
+
+    let m = Matrix::from_iter([[2., 0., 1e6], [0., 4., -1e6], [0., 0., 1.]]);
+    assert_eq!(m.clone() * m.inverse(), Matrix::identity(3, 3));
+
+    let m = Matrix::from_iter([[1., 2., 1000.], [2., 4., 1000.], [0., 0., 1.]]);
+    assert!(m.try_inverse().is_none());
+}
+
+#[test]
+fn matrix_inverse_small_scale() {
+    let m = Matrix::from_iter([
+        [0.01, 0., 0., 0.],
+        [0., 0.01, 0., 0.],
+        [0., 0., 0.01, 0.],
+        [0., 0., 0., 1.],
+    ]);
+    let inv = m.try_inverse().unwrap();
+    assert_eq!(inv[0][0], 100.);
+    assert_eq!(inv[3][3], 1.);
+}
+
+#[test]
+fn matrix4_mul() {
+    let a = Matrix4::new([
+        [1., 2., 3., 4.],
+        [5., 6., 7., 8.],
+        [9., 8., 7., 6.],
+        [5., 4., 3., 2.],
+    ]);
+    let b = Matrix4::new([
+        [-2., 1., 2., 3.],
+        [3., 2., 1., -1.],
+        [4., 3., 6., 5.],
+        [1., 2., 7., 8.],
+    ]);
+    let expected = Matrix4::new([
+        [20., 22., 50., 48.],
+        [44., 54., 114., 108.],
+        [40., 58., 110., 102.],
+        [16., 26., 46., 42.],
+    ]);
+
+    assert_eq!(a * b, expected);
+    let (a_ref, b_ref) = (&a, &b);
+    assert_eq!(a_ref * b_ref, expected);
+    assert_eq!(a * Matrix4::IDENTITY, a);
+}
+
+#[test]
+fn matrix4_mul_tuples() {
+    let m = Matrix4::new([
+        [1., 2., 3., 4.],
+        [2., 4., 4., 2.],
+        [8., 6., 4., 1.],
+        [0., 0., 0., 1.],
+    ]);
+    assert_eq!(
+        m * Tuple::new(1., 2., 3., 1.),
+        Tuple::new(18., 24., 33., 1.)
+    );
+    let m = &m;
+    assert_eq!(m * Point::new(1., 2., 3.), Point::new(18., 24., 33.));
+    assert_eq!(m * Vector::new(1., 2., 3.), Vector::new(14., 22., 32.));
+}
+
+#[test]
+fn matrix4_const_operations() {
+    const M: Matrix4 = Matrix4::translation(5., -3., 2.);
+    const INVERSE: Matrix4 = M.inverse();
+    const TRANSPOSED: Matrix4 = Matrix4::identity().transpose();
+
+    assert_eq!(INVERSE, Matrix4::translation(-5., 3., -2.));
+    assert_eq!(TRANSPOSED, Matrix4::IDENTITY);
+    assert_eq!(
+        M.transpose(),
+        Matrix4::new([
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [5., -3., 2., 1.],
+        ])
+    );
+}
+
+#[test]
+fn matrix4_inverse() {
+    let m = Matrix4::new([
+        [8., -5., 9., 2.],
+        [7., 5., 6., 1.],
+        [-6., 0., 9., 6.],
+        [-3., 0., -9., -4.],
+    ]);
+    assert_eq!(
+        m.inverse(),
+        Matrix4::try_from(Matrix::from(m).inverse()).unwrap()
+    );
+    assert_eq!(m * m.inverse(), Matrix4::IDENTITY);
+
+    let singular = Matrix4::new([
+        [-4., 2., -2., -3.],
+        [9., 6., 2., 6.],
+        [0., -5., 1., -5.],
+        [0., 0., 0., 0.],
+    ]);
+    assert!(singular.try_inverse().is_none());
+}
+
+#[test]
+fn matrix4_inverse_extreme_scales() {
+    for scale in [1e-8, 1e-3, 1e10] {
+        let m = Matrix4::scaling(scale, scale, scale);
+        let inverse = m.try_inverse().unwrap();
+        assert!((inverse[0][0] * scale - 1.).abs() < 1e-6, "{scale}");
+        assert_eq!(inverse[3][3], 1.);
+
+        let dynamic = Matrix::from(m).try_inverse().unwrap();
+        assert!((dynamic[2][2] * scale - 1.).abs() < 1e-6, "{scale}");
+    }
+
+    // Still singular when the rows are huge but linearly dependent.
+    let singular = Matrix4::new([
+        [1e10, 2e10, 0., 0.],
+        [2e10, 4e10, 0., 0.],
+        [0., 0., 1e10, 0.],
+        [0., 0., 0., 1.],
+    ]);
+    assert!(singular.try_inverse().is_none());
+}
+
+#[test]
+fn matrix4_inverse_large_translations() {
+    // Translations have a determinant of 1 however far they move.
+    for offset in [50., 1000., 1e6] {
+        let m = Matrix4::translation(offset, offset, offset);
+        assert_eq!(
+            m.try_inverse().unwrap(),
+            Matrix4::translation(-offset, -offset, -offset)
+        );
+    }
+    let m = Matrix4::scaling(0.25, 0.25, 0.25).translate(500., 0., 500.);
+    assert_eq!(
+        m.try_inverse().unwrap(),
+        Matrix4::translation(-500., 0., -500.).scale(4., 4., 4.)
+    );
+}
+
+#[test]
+fn matrix4_conversions() {
+    let dynamic = Matrix::from_iter([
+        [1., 2., 3., 4.],
+        [5., 6., 7., 8.],
+        [9., 8., 7., 6.],
+        [5., 4., 3., 2.],
+    ]);
+    let fixed = Matrix4::try_from(&dynamic).unwrap();
+    assert_eq!(fixed[1][2], 7.);
+    assert_eq!(Matrix::from(fixed), dynamic);
+
+    let err = Matrix4::try_from(Matrix::identity(3, 3)).unwrap_err();
+    assert_eq!((err.rows, err.cols), (3, 3));
+}
@@ -0,0 +1,317 @@
+use std::any::Any;
+
+use ray_tracer::{
+    obj::{ObjErrorKind, ObjFile, ParseObjError},
+    shape::{Shape, SmoothTriangle, Triangle},
+    tuple::{Point, Vector},
+};
+
+// Children are only reachable as `dyn Shape`, so downcast them to compare
+// their vertices and normals.
+fn assert_triangle(actual: &dyn Shape, expected: &Triangle) {
+    let actual = (actual as &dyn Any)
+        .downcast_ref::<Triangle>()
+        .unwrap_or_else(|| panic!("expected a triangle, found {actual:?}"));
+    assert_eq!(
+        [actual.p1(), actual.p2(), actual.p3()],
+        [expected.p1(), expected.p2(), expected.p3()]
+    );
+}
+
+fn assert_smooth_triangle(actual: &dyn Shape, expected: &SmoothTriangle) {
+    let actual = (actual as &dyn Any)
+        .downcast_ref::<SmoothTriangle>()
+        .unwrap_or_else(|| panic!("expected a smooth triangle, found {actual:?}"));
+    assert_eq!(
+        [actual.p1(), actual.p2(), actual.p3()],
+        [expected.p1(), expected.p2(), expected.p3()]
+    );
+    assert_eq!(
+        [actual.n1(), actual.n2(), actual.n3()],
+        [expected.n1(), expected.n2(), expected.n3()]
+    );
+}
+
+#[test]
+fn ignore_unrecognized_lines() {
+    let input = "There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.";
+    let obj = ObjFile::parse(input).unwrap();
+    assert_eq!(obj.ignored, 5);
+    assert!(obj.vertices.is_empty());
+}
+
+#[test]
+fn comments_and_blank_lines_are_not_counted() {
+    let input = "# a comment
+
+v 1 2 3 # trailing comment
+   ";
+    let obj = ObjFile::parse(input).unwrap();
+    assert_eq!(obj.ignored, 0);
+    assert_eq!(obj.vertices, [Point::new(1., 2., 3.)]);
+}
+
+#[test]
+fn vertex_records() {
+    let input = "v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0 1.0";
+    let obj = ObjFile::parse(input).unwrap();
+    assert_eq!(
+        obj.vertices,
+        [
+            Point::new(-1., 1., 0.),
+            Point::new(-1., 0.5, 0.),
+            Point::new(1., 0., 0.),
+            Point::new(1., 1., 0.),
+        ]
+    );
+}
+
+#[test]
+fn vertex_normal_and_texture_records() {
+    let input = "vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3
+vt 0.5
+vt 0.25 0.75 1";
+    let obj = ObjFile::parse(input).unwrap();
+    assert_eq!(
+        obj.normals,
+        [
+            Vector::new(0., 0., 1.),
+            Vector::new(0.707, 0., -0.707),
+            Vector::new(1., 2., 3.),
+        ]
+    );
+    assert_eq!(
+        obj.texture_vertices,
+        [Point::new(0.5, 0., 0.), Point::new(0.25, 0.75, 1.)]
+    );
+}
+
+#[test]
+fn triangle_faces() {
+    let input = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4";
+    let obj = ObjFile::parse(input).unwrap();
+    let [t1, t2] = obj.default_group().children() else {
+        panic!("expected two triangles");
+    };
+    let [p1, p2, p3, p4] = obj.vertices[..] else {
+        unreachable!()
+    };
+    assert_triangle(t1.as_ref(), &Triangle::new(p1, p2, p3));
+    assert_triangle(t2.as_ref(), &Triangle::new(p1, p3, p4));
+}
+
+#[test]
+fn triangulate_polygons() {
+    let input = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+
+f 1 2 3 4 5";
+    let obj = ObjFile::parse(input).unwrap();
+    let [t1, t2, t3] = obj.default_group().children() else {
+        panic!("expected three triangles");
+    };
+    let [p1, p2, p3, p4, p5] = obj.vertices[..] else {
+        unreachable!()
+    };
+    assert_triangle(t1.as_ref(), &Triangle::new(p1, p2, p3));
+    assert_triangle(t2.as_ref(), &Triangle::new(p1, p3, p4));
+    assert_triangle(t3.as_ref(), &Triangle::new(p1, p4, p5));
+}
+
+#[test]
+fn triangles_in_named_groups() {
+    let input = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4
+g FirstGroup
+f 2 3 4";
+    let obj = ObjFile::parse(input).unwrap();
+    assert!(obj.default_group().is_empty());
+    assert_eq!(
+        obj.group_names().collect::<Vec<_>>(),
+        ["FirstGroup", "SecondGroup"]
+    );
+
+    let [p1, p2, p3, p4] = obj.vertices[..] else {
+        unreachable!()
+    };
+    let [t1, t3] = obj.group("FirstGroup").unwrap().children() else {
+        panic!("expected two triangles");
+    };
+    let [t2] = obj.group("SecondGroup").unwrap().children() else {
+        panic!("expected one triangle");
+    };
+    assert_triangle(t1.as_ref(), &Triangle::new(p1, p2, p3));
+    assert_triangle(t2.as_ref(), &Triangle::new(p1, p3, p4));
+    assert_triangle(t3.as_ref(), &Triangle::new(p2, p3, p4));
+    assert!(obj.group("ThirdGroup").is_none());
+}
+
+#[test]
+fn convert_to_group() {
+    let input = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+    let group = ObjFile::parse(input).unwrap().into_group();
+    let [triangle, first, second] = group.children() else {
+        panic!("expected a triangle and two groups");
+    };
+    assert!(triangle.children().is_empty());
+    assert_eq!(first.children().len(), 1);
+    assert_eq!(second.children().len(), 1);
+}
+
+#[test]
+fn faces_with_normals() {
+    let input = "v 0 1 0
+v -1 0 0
+v 1 0 0
+
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+
+f 1//3 2//1 3//2
+f 1/0/3 2/102/1 3/14/2";
+    let err = ObjFile::parse(input).unwrap_err();
+    assert_eq!(err.line, 10);
+
+    let input = input.replace("f 1/0/3 2/102/1 3/14/2", "vt 0 0\nf 1/1/3 2/1/1 3/-1/2");
+    let obj = ObjFile::parse(&input).unwrap();
+    let [t1, t2] = obj.default_group().children() else {
+        panic!("expected two triangles");
+    };
+    let expected = SmoothTriangle::new(
+        obj.vertices[0],
+        obj.vertices[1],
+        obj.vertices[2],
+        obj.normals[2],
+        obj.normals[0],
+        obj.normals[1],
+    );
+    assert_smooth_triangle(t1.as_ref(), &expected);
+    assert_smooth_triangle(t2.as_ref(), &expected);
+}
+
+#[test]
+fn texture_only_faces_are_flat() {
+    let input = "v 0 1 0
+v -1 0 0
+v 1 0 0
+vt 0 0
+vn 0 1 0
+f 1/1 2/1 3/1
+f 1/1/1 2/1 3/1/1";
+    let obj = ObjFile::parse(input).unwrap();
+    let [p1, p2, p3] = obj.vertices[..] else {
+        unreachable!()
+    };
+    for child in obj.default_group().children() {
+        assert_triangle(child.as_ref(), &Triangle::new(p1, p2, p3));
+    }
+}
+
+#[test]
+fn negative_indices() {
+    let input = "v -1 1 0
+v -1 0 0
+v 1 0 0
+f -3 -2 -1
+v 1 1 0
+f 1 -2 -1";
+    let obj = ObjFile::parse(input).unwrap();
+    let [t1, t2] = obj.default_group().children() else {
+        panic!("expected two triangles");
+    };
+    let [p1, p2, p3, p4] = obj.vertices[..] else {
+        unreachable!()
+    };
+    assert_triangle(t1.as_ref(), &Triangle::new(p1, p2, p3));
+    assert_triangle(t2.as_ref(), &Triangle::new(p1, p3, p4));
+}
+
+#[test]
+fn malformed_lines_report_line_numbers() {
+    let cases = [
+        (
+            "v 1 2",
+            1,
+            ObjErrorKind::WrongArgumentCount {
+                expected: 3..=4,
+                found: 2,
+            },
+        ),
+        (
+            "v 1 2 3\nvn 0 x 1",
+            2,
+            ObjErrorKind::InvalidNumber("x".into()),
+        ),
+        ("v 1 2 3 abc", 1, ObjErrorKind::InvalidNumber("abc".into())),
+        ("vt 0.5 0.5 w", 1, ObjErrorKind::InvalidNumber("w".into())),
+        (
+            "v 1 2 3\nv 4 5 6\n\nf 1 2",
+            4,
+            ObjErrorKind::TooFewFaceVertices(2),
+        ),
+        ("v 1 2 3\nf 1 1 2", 2, ObjErrorKind::IndexOutOfRange(2)),
+        ("v 1 2 3\nf 1 1 -2", 2, ObjErrorKind::IndexOutOfRange(-2)),
+        ("v 1 2 3\nf 0 1 1", 2, ObjErrorKind::IndexOutOfRange(0)),
+        (
+            "v 1 2 3\nf 1 1 1//",
+            2,
+            ObjErrorKind::InvalidNumber("".into()),
+        ),
+        (
+            "v 1 2 3\nf 1 1 1/1/1/1",
+            2,
+            ObjErrorKind::InvalidFaceVertex("1/1/1/1".into()),
+        ),
+    ];
+
+    for (input, line, kind) in cases {
+        assert_eq!(
+            ObjFile::parse(input).unwrap_err(),
+            ParseObjError {
+                line,
+                kind,
+            }
+        );
+    }
+}
+
+#[test]
+fn error_display() {
+    let err = ObjFile::parse("\n\nvn 1 2").unwrap_err();
+    assert_eq!(err.to_string(), "line 3: expected 3 values, found 2");
+}
@@ -0,0 +1,182 @@
+use ray_tracer::{
+    color::Color,
+    matrix::Matrix4,
+    pattern::{Blend, Checker, Gradient, Pattern, PatternData, RadialGradient, Ring, Stripe},
+    shape::{Shape, Sphere},
+    tuple::Point,
+};
+
+const WHITE: Color = Color::WHITE;
+const BLACK: Color = Color::BLACK;
+
+#[derive(Debug, Default)]
+struct TestPattern {
+    data: PatternData,
+}
+
+impl Pattern for TestPattern {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        Color::new(point.x, point.y, point.z)
+    }
+}
+
+#[test]
+fn default_pattern_transform() {
+    let p = TestPattern::default();
+    assert_eq!(*p.transform(), Matrix4::IDENTITY);
+}
+
+#[test]
+fn assign_pattern_transform() {
+    let p = TestPattern::default().with_transform(Matrix4::translation(1., 2., 3.));
+    assert_eq!(*p.transform(), Matrix4::translation(1., 2., 3.));
+}
+
+#[test]
+fn pattern_with_object_transform() {
+    let shape = Sphere::new().with_transform(Matrix4::scaling(2., 2., 2.));
+    let p = TestPattern::default();
+    assert_eq!(
+        p.pattern_at_shape(&shape, Point::new(2., 3., 4.)),
+        Color::new(1., 1.5, 2.)
+    );
+}
+
+#[test]
+fn pattern_with_pattern_transform() {
+    let shape = Sphere::new();
+    let p = TestPattern::default().with_transform(Matrix4::scaling(2., 2., 2.));
+    assert_eq!(
+        p.pattern_at_shape(&shape, Point::new(2., 3., 4.)),
+        Color::new(1., 1.5, 2.)
+    );
+}
+
+#[test]
+fn pattern_with_object_and_pattern_transform() {
+    let shape = Sphere::new().with_transform(Matrix4::scaling(2., 2., 2.));
+    let p = TestPattern::default().with_transform(Matrix4::translation(0.5, 1., 1.5));
+    assert_eq!(
+        p.pattern_at_shape(&shape, Point::new(2.5, 3., 3.5)),
+        Color::new(0.75, 0.5, 0.25)
+    );
+}
+
+#[test]
+fn stripe_pattern() {
+    let p = Stripe::new(WHITE, BLACK);
+
+    assert_eq!(p.pattern_at(Point::new(0., 1., 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(0., 2., 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(0., 0., 1.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(0., 0., 2.)), WHITE);
+
+    assert_eq!(p.pattern_at(Point::new(0., 0., 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(0.9, 0., 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(1., 0., 0.)), BLACK);
+    assert_eq!(p.pattern_at(Point::new(-0.1, 0., 0.)), BLACK);
+    assert_eq!(p.pattern_at(Point::new(-1., 0., 0.)), BLACK);
+    assert_eq!(p.pattern_at(Point::new(-1.1, 0., 0.)), WHITE);
+}
+
+#[test]
+fn gradient_pattern() {
+    let p = Gradient::new(WHITE, BLACK);
+    assert_eq!(p.pattern_at(Point::new(0., 0., 0.)), WHITE);
+    assert_eq!(
+        p.pattern_at(Point::new(0.25, 0., 0.)),
+        Color::new(0.75, 0.75, 0.75)
+    );
+    assert_eq!(
+        p.pattern_at(Point::new(0.5, 0., 0.)),
+        Color::new(0.5, 0.5, 0.5)
+    );
+    assert_eq!(
+        p.pattern_at(Point::new(0.75, 0., 0.)),
+        Color::new(0.25, 0.25, 0.25)
+    );
+}
+
+#[test]
+fn ring_pattern() {
+    let p = Ring::new(WHITE, BLACK);
+    assert_eq!(p.pattern_at(Point::new(0., 0., 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(1., 0., 0.)), BLACK);
+    assert_eq!(p.pattern_at(Point::new(0., 0., 1.)), BLACK);
+    assert_eq!(p.pattern_at(Point::new(0.708, 0., 0.708)), BLACK);
+}
+
+#[test]
+fn checker_pattern() {
+    let p = Checker::new(WHITE, BLACK);
+
+    assert_eq!(p.pattern_at(Point::new(0., 0., 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(0.99, 0., 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(1.01, 0., 0.)), BLACK);
+
+    assert_eq!(p.pattern_at(Point::new(0., 0.99, 0.)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(0., 1.01, 0.)), BLACK);
+
+    assert_eq!(p.pattern_at(Point::new(0., 0., 0.99)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(0., 0., 1.01)), BLACK);
+}
+
+#[test]
+fn radial_gradient_pattern() {
+    let p = RadialGradient::new(WHITE, BLACK);
+    assert_eq!(p.pattern_at(Point::new(0., 0., 0.)), WHITE);
+    assert_eq!(
+        p.pattern_at(Point::new(0.3, 0., 0.4)),
+        Color::new(0.5, 0.5, 0.5)
+    );
+    assert_eq!(
+        p.pattern_at(Point::new(0., 5., 1.25)),
+        Color::new(0.75, 0.75, 0.75)
+    );
+}
+
+#[test]
+fn blend_pattern() {
+    let red = Color::new(1., 0., 0.);
+    let blue = Color::new(0., 0., 1.);
+    let p = Blend::new(
+        Stripe::new(red, WHITE),
+        Stripe::new(blue, WHITE).with_transform(Matrix4::rotation_y(std::f32::consts::FRAC_PI_2)),
+    );
+
+    assert_eq!(
+        p.pattern_at(Point::new(0.5, 0., -0.5)),
+        Color::new(0.5, 0., 0.5)
+    );
+    assert_eq!(
+        p.pattern_at(Point::new(1.5, 0., -1.5)),
+        Color::new(1., 1., 1.)
+    );
+
+    let p = Blend::new(WHITE, BLACK).with_factor(0.25);
+    assert_eq!(
+        p.pattern_at(Point::new(0., 0., 0.)),
+        Color::new(0.75, 0.75, 0.75)
+    );
+}
+
+#[test]
+fn nested_pattern() {
+    let red = Color::new(1., 0., 0.);
+    let p = Checker::new(
+        Stripe::new(WHITE, BLACK),
+        Stripe::new(red, BLACK).with_transform(Matrix4::scaling(0.5, 0.5, 0.5)),
+    );
+
+    assert_eq!(p.pattern_at(Point::new(0.5, 0., 0.5)), WHITE);
+    assert_eq!(p.pattern_at(Point::new(1.25, 0., 0.5)), red);
+    assert_eq!(p.pattern_at(Point::new(1.75, 0., 0.5)), BLACK);
+}
@@ -0,0 +1,57 @@
+use std::ptr;
+
+use ray_tracer::{
+    intersection::Intersections,
+    ray::Ray,
+    shape::{Plane, Shape},
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn plane_normal_is_constant() {
+    let p = Plane::new();
+    let up = Vector::new(0., 1., 0.);
+    assert_eq!(p.local_normal_at(Point::new(0., 0., 0.)), up);
+    assert_eq!(p.local_normal_at(Point::new(10., 0., -10.)), up);
+    assert_eq!(p.local_normal_at(Point::new(-5., 0., 150.)), up);
+}
+
+#[test]
+fn intersect_parallel_ray() {
+    let p = Plane::new();
+    let r = Ray::new(Point::new(0., 10., 0.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    p.local_intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn intersect_coplanar_ray() {
+    let p = Plane::new();
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    p.local_intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn intersect_from_above() {
+    let p = Plane::new();
+    let r = Ray::new(Point::new(0., 1., 0.), Vector::new(0., -1., 0.));
+    let mut xs = Intersections::new();
+    p.local_intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t, 1.);
+    assert!(ptr::addr_eq(xs[0].object, &p));
+}
+
+#[test]
+fn intersect_from_below() {
+    let p = Plane::new();
+    let r = Ray::new(Point::new(0., -1., 0.), Vector::new(0., 1., 0.));
+    let mut xs = Intersections::new();
+    p.local_intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t, 1.);
+    assert!(ptr::addr_eq(xs[0].object, &p));
+}
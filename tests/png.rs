@@ -0,0 +1,272 @@
+use ray_tracer::{
+    canvas::{Canvas, ImageError, PngFormat},
+    color::Color,
+    compress::{InflateError, crc32, zlib_compress},
+};
+
+fn sample_canvas() -> Canvas {
+    let mut c = Canvas::new(9, 6);
+    for y in 0..6 {
+        for x in 0..9 {
+            c.write_pixel(
+                x,
+                y,
+                Color::new(x as f32 / 8., y as f32 / 5., ((x + y) % 3) as f32 / 2.),
+            );
+        }
+    }
+    c
+}
+
+fn write(c: &Canvas, format: PngFormat) -> Vec<u8> {
+    let mut out = Vec::new();
+    c.write_png(&mut out, format).unwrap();
+    out
+}
+
+fn chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut out = (data.len() as u32).to_be_bytes().to_vec();
+    let mut checked = kind.to_vec();
+    checked.extend_from_slice(data);
+    out.extend_from_slice(&checked);
+    out.extend(crc32(&checked).to_be_bytes());
+    out
+}
+
+fn png(
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: u8,
+    extra: &[u8],
+    raw: &[u8],
+) -> Vec<u8> {
+    let mut header = width.to_be_bytes().to_vec();
+    header.extend(height.to_be_bytes());
+    header.extend([bit_depth, color_type, 0, 0, 0]);
+
+    let mut out = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+    out.extend(chunk(b"IHDR", &header));
+    out.extend_from_slice(extra);
+    out.extend(chunk(b"IDAT", &zlib_compress(raw)));
+    out.extend(chunk(b"IEND", &[]));
+    out
+}
+
+#[test]
+fn written_png_starts_with_signature_and_header() {
+    let data = write(&sample_canvas(), PngFormat::Rgba16);
+    assert_eq!(&data[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&data[8..16], b"\0\0\0\x0dIHDR");
+    assert_eq!(&data[16..29], [0, 0, 0, 9, 0, 0, 0, 6, 16, 6, 0, 0, 0]);
+    assert!(data.ends_with(&chunk(b"IEND", &[])));
+}
+
+#[test]
+fn round_trip_8_bit() {
+    let c = sample_canvas();
+    for format in [PngFormat::Rgb8, PngFormat::Rgba8] {
+        let read = Canvas::read_png(write(&c, format).as_slice()).unwrap();
+        assert_eq!((read.width, read.height), (9, 6));
+        for (a, b) in read.pixels.iter().zip(&c.pixels) {
+            assert_eq!(a.to_rgb8(), b.to_rgb8(), "{format:?}");
+        }
+    }
+}
+
+#[test]
+fn round_trip_16_bit() {
+    let c = sample_canvas();
+    for format in [PngFormat::Rgb16, PngFormat::Rgba16] {
+        let read = Canvas::read_png(write(&c, format).as_slice()).unwrap();
+        for (a, b) in read.pixels.iter().zip(&c.pixels) {
+            assert_eq!(a.quantize(65535), b.quantize(65535), "{format:?}");
+        }
+    }
+}
+
+#[test]
+fn round_trip_clamps_out_of_range_colors() {
+    let mut c = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(1.5, -0.5, 0.5));
+    c.write_pixel(1, 0, Color::new(0., 2., 1.));
+    let read = Canvas::read_png(write(&c, PngFormat::Rgb8).as_slice()).unwrap();
+    assert_eq!(read.pixel_at(0, 0).to_rgb8(), [255, 0, 128]);
+    assert_eq!(read.pixel_at(1, 0), Color::new(0., 1., 1.));
+}
+
+#[test]
+fn empty_canvas_cannot_be_written() {
+    assert!(
+        Canvas::new(0, 3)
+            .write_png(Vec::new(), PngFormat::Rgb8)
+            .is_err()
+    );
+}
+
+#[test]
+fn read_grayscale() {
+    // Two rows of four 2-bit pixels, the second stored with the Up filter.
+    let raw = [0, 0b0001_1011, 2, 0b1110_0100];
+    let c = Canvas::read_png(png(4, 2, 2, 0, &[], &raw).as_slice()).unwrap();
+    let gray = |v: f32| Color::new(v, v, v);
+    assert_eq!(
+        c.pixels,
+        [
+            gray(0.),
+            gray(1. / 3.),
+            gray(2. / 3.),
+            gray(1.),
+            gray(1.),
+            gray(1.),
+            gray(1.),
+            gray(1.),
+        ]
+    );
+}
+
+#[test]
+fn read_16_bit_grayscale_with_alpha() {
+    let raw = [0, 0x80, 0x00, 0x12, 0x34, 0xFF, 0xFF, 0, 0];
+    let c = Canvas::read_png(png(2, 1, 16, 4, &[], &raw).as_slice()).unwrap();
+    let half = 32768. / 65535.;
+    assert_eq!(c.pixels, [Color::new(half, half, half), Color::WHITE]);
+}
+
+#[test]
+fn read_palette() {
+    let palette = chunk(b"PLTE", &[255, 0, 0, 0, 0, 255, 0, 255, 0]);
+    // An ancillary chunk the reader does not know is skipped.
+    let extra = [palette, chunk(b"tEXt", b"Comment\0hello")].concat();
+    let raw = [0, 0x12, 0x00];
+    let c = Canvas::read_png(png(3, 1, 4, 3, &extra, &raw).as_slice()).unwrap();
+    assert_eq!(
+        c.pixels,
+        [
+            Color::new(0., 0., 1.),
+            Color::new(0., 1., 0.),
+            Color::new(1., 0., 0.),
+        ]
+    );
+}
+
+#[test]
+fn read_every_filter() {
+    // Each row holds the same colors, stored with filters None to Paeth.
+    let row = [10_u8, 20, 30, 40, 50, 60, 250, 5, 128];
+    let mut raw = Vec::new();
+    let mut previous = [0_u8; 9];
+    for filter in 0..5 {
+        raw.push(filter);
+        for i in 0..9 {
+            let a = if i >= 3 { row[i - 3] } else { 0 };
+            let b = previous[i];
+            let c = if i >= 3 { previous[i - 3] } else { 0 };
+            let prediction = match filter {
+                1 => a,
+                2 => b,
+                3 => ((u16::from(a) + u16::from(b)) / 2) as u8,
+                4 => {
+                    let p = i16::from(a) + i16::from(b) - i16::from(c);
+                    let (pa, pb, pc) = (
+                        (p - i16::from(a)).abs(),
+                        (p - i16::from(b)).abs(),
+                        (p - i16::from(c)).abs(),
+                    );
+                    if pa <= pb && pa <= pc {
+                        a
+                    } else if pb <= pc {
+                        b
+                    } else {
+                        c
+                    }
+                }
+                _ => 0,
+            };
+            raw.push(row[i].wrapping_sub(prediction));
+        }
+        previous = row;
+    }
+
+    let c = Canvas::read_png(png(3, 5, 8, 2, &[], &raw).as_slice()).unwrap();
+    for (y, pixels) in c.rows().enumerate() {
+        let bytes: Vec<_> = pixels.iter().flat_map(Color::to_rgb8).collect();
+        assert_eq!(bytes, row, "filter {y}");
+    }
+}
+
+#[test]
+fn malformed_images() {
+    let good = write(&sample_canvas(), PngFormat::Rgb8);
+
+    let mut bad_crc = good.clone();
+    bad_crc[20] ^= 1;
+    let mut interlaced = png(1, 1, 8, 2, &[], &[0, 0, 0, 0]);
+    interlaced[28] = 1;
+    // Re-checksum the header so only the interlace flag is wrong.
+    let crc = crc32(&interlaced[12..29]).to_be_bytes();
+    interlaced[29..33].copy_from_slice(&crc);
+
+    type Check = fn(&ImageError) -> bool;
+    let cases: [(Vec<u8>, Check); 9] = [
+        (b"\x89PNG".to_vec(), |e| matches!(e, ImageError::Truncated)),
+        (b"P6\n1 1\n255\n\0\0\0".to_vec(), |e| {
+            matches!(e, ImageError::UnknownFormat)
+        }),
+        (bad_crc, |e| matches!(e, ImageError::ChecksumMismatch)),
+        (good[..good.len() - 20].to_vec(), |e| {
+            matches!(e, ImageError::Truncated)
+        }),
+        (interlaced, |e| matches!(e, ImageError::Unsupported(_))),
+        (png(0, 1, 8, 2, &[], &[]), |e| {
+            matches!(
+                e,
+                ImageError::InvalidDimensions {
+                    width: 0,
+                    height: 1
+                }
+            )
+        }),
+        (png(1, 1, 4, 2, &[], &[0, 0]), |e| {
+            matches!(e, ImageError::Malformed(_))
+        }),
+        (png(2, 2, 8, 2, &[], &[0, 1, 2, 3, 4, 5, 6]), |e| {
+            matches!(e, ImageError::Truncated)
+        }),
+        (png(1, 1, 8, 3, &[], &[0, 0]), |e| {
+            matches!(e, ImageError::Malformed(_))
+        }),
+    ];
+
+    for (i, (data, expected)) in cases.into_iter().enumerate() {
+        let err = Canvas::read_png(data.as_slice()).unwrap_err();
+        assert!(expected(&err), "case {i}: {err:?}");
+    }
+}
+
+#[test]
+fn corrupt_image_data_is_a_compression_error() {
+    let data = png(1, 1, 8, 2, &[], &[0, 0, 0, 0]);
+    let mut idat = data.clone();
+    // Break the zlib header inside IDAT, then fix up that chunk's CRC.
+    let start = 33 + 8;
+    idat[start] = 0x79;
+    let len = u32::from_be_bytes(data[33..37].try_into().unwrap()) as usize;
+    let crc = crc32(&idat[37..start + len]).to_be_bytes();
+    idat[start + len..start + len + 4].copy_from_slice(&crc);
+
+    let err = Canvas::read_png(idat.as_slice()).unwrap_err();
+    assert!(matches!(err, ImageError::Compression(_)), "{err:?}");
+}
+
+#[test]
+fn image_data_larger_than_the_header_is_rejected() {
+    // 1000 rows of zeros compress to a few bytes, but the header only has
+    // room for one.
+    let data = png(1, 1, 8, 2, &[], &[0; 4000]);
+    let err = Canvas::read_png(data.as_slice()).unwrap_err();
+    assert!(
+        matches!(err, ImageError::Compression(InflateError::TooLarge)),
+        "{err:?}"
+    );
+}
@@ -0,0 +1,273 @@
+use std::io::{self, Read};
+
+use ray_tracer::{
+    canvas::{Canvas, ImageError},
+    color::Color,
+};
+
+fn read(data: &[u8]) -> Result<Canvas, ImageError> {
+    Canvas::read_ppm(data)
+}
+
+fn gray(v: f32) -> Color {
+    Color::new(v, v, v)
+}
+
+#[test]
+fn read_plain_bitmap() {
+    let c = read(b"P1\n# a comment\n3 2\n0 1 0\n101").unwrap();
+    assert_eq!((c.width, c.height), (3, 2));
+    assert_eq!(
+        c.pixels,
+        [
+            Color::WHITE,
+            Color::BLACK,
+            Color::WHITE,
+            Color::BLACK,
+            Color::WHITE,
+            Color::BLACK,
+        ]
+    );
+}
+
+#[test]
+fn read_raw_bitmap() {
+    // Rows of 10 pixels are padded out to two bytes each, and the padding
+    // bits are ignored.
+    let mut data = b"P4 10 2\n".to_vec();
+    data.extend([0b1000_0000, 0b0100_0000, 0b0000_0001, 0b0011_1111]);
+    let c = read(&data).unwrap();
+    assert_eq!(c.pixel_at(0, 0), Color::BLACK);
+    assert_eq!(c.pixel_at(1, 0), Color::WHITE);
+    assert_eq!(c.pixel_at(9, 0), Color::BLACK);
+    assert_eq!(c.pixel_at(7, 1), Color::BLACK);
+    assert_eq!(c.pixel_at(8, 1), Color::WHITE);
+    assert_eq!(c.pixels.iter().filter(|&&p| p == Color::BLACK).count(), 3);
+}
+
+#[test]
+fn read_plain_graymap() {
+    let c = read(b"P2 2 2 15\n0 15\n  5\t\t10\n").unwrap();
+    assert_eq!(c.pixels, [gray(0.), gray(1.), gray(1. / 3.), gray(2. / 3.)]);
+}
+
+#[test]
+fn read_raw_graymap() {
+    let mut data = b"P5\n3 1\n255\n".to_vec();
+    data.extend([0, 51, 255]);
+    let c = read(&data).unwrap();
+    assert_eq!(c.pixels, [gray(0.), gray(0.2), gray(1.)]);
+}
+
+#[test]
+fn read_plain_pixmap_with_comments() {
+    let data = b"P3 # magic
+# size follows
+2 # width
+1
+# maxval
+255
+255 0 0   # red
+0 0 128
+";
+    let c = read(data).unwrap();
+    assert_eq!(
+        c.pixels,
+        [Color::new(1., 0., 0.), Color::new(0., 0., 128. / 255.)]
+    );
+}
+
+#[test]
+fn raw_data_may_start_with_whitespace_bytes() {
+    let mut data = b"P6\n1 1\n255\n".to_vec();
+    data.extend([b' ', b'\n', b'#']);
+    let c = read(&data).unwrap();
+    assert_eq!(c.pixels[0].to_rgb8(), [b' ', b'\n', b'#']);
+}
+
+fn sample_canvas() -> Canvas {
+    let mut c = Canvas::new(7, 5);
+    for y in 0..5 {
+        for x in 0..7 {
+            c.write_pixel(
+                x,
+                y,
+                Color::new(x as f32 / 6., y as f32 / 4., (x * y) as f32 / 24.),
+            );
+        }
+    }
+    c
+}
+
+#[test]
+fn round_trip_written_ppm() {
+    let c = sample_canvas();
+    let rgb8: Vec<_> = c.pixels.iter().map(Color::to_rgb8).collect();
+
+    let p3 = read(c.to_ppm().as_bytes()).unwrap();
+    assert_eq!((p3.width, p3.height), (7, 5));
+    assert_eq!(
+        p3.pixels.iter().map(Color::to_rgb8).collect::<Vec<_>>(),
+        rgb8
+    );
+
+    let mut out = Vec::new();
+    c.write_p6(&mut out, 255).unwrap();
+    let p6 = read(&out).unwrap();
+    assert_eq!(
+        p6.pixels.iter().map(Color::to_rgb8).collect::<Vec<_>>(),
+        rgb8
+    );
+}
+
+#[test]
+fn round_trip_16_bit() {
+    let c = sample_canvas();
+    for maxval in [1000, 65535] {
+        let mut out = Vec::new();
+        c.write_p6(&mut out, maxval).unwrap();
+        let p6 = read(&out).unwrap();
+        let mut out = Vec::new();
+        c.write_p3(&mut out, maxval).unwrap();
+        let p3 = read(&out).unwrap();
+
+        for ((a, b), original) in p6.pixels.iter().zip(&p3.pixels).zip(&c.pixels) {
+            assert_eq!(a.quantize(maxval), original.quantize(maxval));
+            assert_eq!(b.quantize(maxval), original.quantize(maxval));
+        }
+    }
+}
+
+fn pfm(header: &str, values: &[f32], little_endian: bool) -> Vec<u8> {
+    let mut data = header.as_bytes().to_vec();
+    for value in values {
+        if little_endian {
+            data.extend(value.to_le_bytes());
+        } else {
+            data.extend(value.to_be_bytes());
+        }
+    }
+    data
+}
+
+#[test]
+fn read_color_pfm() {
+    // Rows are stored bottom to top.
+    let values = [0., 0.5, 1., 2., 3., 4., 10., 20., 30., -1., 0.25, 100.];
+    for (header, little_endian) in [("PF\n2 2\n-1.0\n", true), ("PF\n2 2\n1.0\n", false)] {
+        let c = read(&pfm(header, &values, little_endian)).unwrap();
+        assert_eq!(c.pixel_at(0, 1), Color::new(0., 0.5, 1.));
+        assert_eq!(c.pixel_at(1, 1), Color::new(2., 3., 4.));
+        assert_eq!(c.pixel_at(0, 0), Color::new(10., 20., 30.));
+        assert_eq!(c.pixel_at(1, 0), Color::new(-1., 0.25, 100.));
+    }
+}
+
+#[test]
+fn read_grayscale_pfm() {
+    let c = read(&pfm("Pf 3 1 -4\n", &[0.5, 1.5, 8.], true)).unwrap();
+    assert_eq!(c.pixels, [gray(0.5), gray(1.5), gray(8.)]);
+}
+
+#[test]
+fn round_trip_written_pfm() {
+    let mut c = sample_canvas();
+    c.write_pixel(2, 1, Color::new(-3., 250., 1e-7));
+
+    let mut out = Vec::new();
+    c.write_pfm(&mut out).unwrap();
+    assert!(out.starts_with(b"PF\n7 5\n-1.0\n"));
+    // The first value written is the red channel of the bottom-left pixel.
+    assert_eq!(out[12..16], c.pixel_at(0, 4).r.to_le_bytes());
+
+    let read = read(&out).unwrap();
+    assert_eq!((read.width, read.height), (7, 5));
+    for (a, b) in read.pixels.iter().zip(&c.pixels) {
+        assert_eq!([a.r, a.g, a.b], [b.r, b.g, b.b]);
+    }
+}
+
+#[test]
+fn malformed_images() {
+    type Check = fn(&ImageError) -> bool;
+    let cases: [(&[u8], Check); 14] = [
+        (b"", |e| matches!(e, ImageError::Truncated)),
+        (b"P7\n1 1\n", |e| matches!(e, ImageError::UnknownFormat)),
+        (b"GIF89a", |e| matches!(e, ImageError::UnknownFormat)),
+        (b"P3\n2", |e| matches!(e, ImageError::Truncated)),
+        (b"P3\nx 2 255\n", |e| {
+            matches!(e, ImageError::InvalidHeader("width"))
+        }),
+        (b"P3\n2 -1 255\n", |e| {
+            matches!(e, ImageError::InvalidHeader("height"))
+        }),
+        (b"P3\n0 2 255\n", |e| {
+            matches!(
+                e,
+                ImageError::InvalidDimensions {
+                    width: 0,
+                    height: 2
+                }
+            )
+        }),
+        (b"P2\n1 1 0\n0", |e| {
+            matches!(e, ImageError::InvalidMaxval(0))
+        }),
+        (b"P5\n1 1 70000\n\0\0", |e| {
+            matches!(e, ImageError::InvalidMaxval(70000))
+        }),
+        (b"P2\n2 1 15\n3 16\n", |e| {
+            matches!(
+                e,
+                ImageError::InvalidSample {
+                    offset: 12
+                }
+            )
+        }),
+        (b"P1\n2 1\n1 2\n", |e| {
+            matches!(
+                e,
+                ImageError::InvalidSample {
+                    offset: 9
+                }
+            )
+        }),
+        (b"P6\n2 1\n255\n\0\0\0", |e| {
+            matches!(e, ImageError::Truncated)
+        }),
+        (b"P6\n1 1\n255", |e| matches!(e, ImageError::Truncated)),
+        (b"PF\n1 1\n0\n\0\0\0\0\0\0\0\0\0\0\0\0", |e| {
+            matches!(e, ImageError::InvalidHeader("scale"))
+        }),
+    ];
+
+    for (data, expected) in cases {
+        let err = read(data).unwrap_err();
+        assert!(
+            expected(&err),
+            "{:?}: {err:?}",
+            String::from_utf8_lossy(data)
+        );
+    }
+}
+
+#[test]
+fn huge_dimensions_fail_before_allocating() {
+    let err = read(b"P6\n60000 60000\n255\n\0\0\0").unwrap_err();
+    assert!(matches!(err, ImageError::Truncated));
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk on fire"))
+    }
+}
+
+#[test]
+fn io_errors_are_passed_through() {
+    let err = Canvas::read_ppm(FailingReader).unwrap_err();
+    assert!(matches!(err, ImageError::Io(_)));
+    assert_eq!(err.to_string(), "disk on fire");
+}
@@ -0,0 +1,49 @@
+use ray_tracer::{
+    matrix::Matrix4,
+    ray::Ray,
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn create_ray() {
+    let origin = Point::new(1., 2., 3.);
+    let direction = Vector::new(4., 5., 6.);
+    let r = Ray::new(origin, direction);
+    assert_eq!(r.origin, origin);
+    assert_eq!(r.direction, direction);
+}
+
+#[test]
+fn point_from_distance() {
+    let r = Ray::new(Point::new(2., 3., 4.), Vector::new(1., 0., 0.));
+    assert_eq!(r.position(0.), Point::new(2., 3., 4.));
+    assert_eq!(r.position(1.), Point::new(3., 3., 4.));
+    assert_eq!(r.position(-1.), Point::new(1., 3., 4.));
+    assert_eq!(r.position(2.5), Point::new(4.5, 3., 4.));
+}
+
+#[test]
+fn translate_ray() {
+    let r = Ray::new(Point::new(1., 2., 3.), Vector::new(0., 1., 0.));
+    let r2 = r.transform(&Matrix4::translation(3., 4., 5.));
+    assert_eq!(r2.origin, Point::new(4., 6., 8.));
+    assert_eq!(r2.direction, Vector::new(0., 1., 0.));
+}
+
+#[test]
+fn scale_ray() {
+    let r = Ray::new(Point::new(1., 2., 3.), Vector::new(0., 1., 0.));
+    let r2 = r.transform(&Matrix4::scaling(2., 3., 4.));
+    assert_eq!(r2.origin, Point::new(2., 6., 12.));
+    assert_eq!(r2.direction, Vector::new(0., 3., 0.));
+}
+
+#[test]
+fn compose_ray_transforms() {
+    let r = Ray::new(Point::new(1., 0., 1.), Vector::new(0., 0., 1.));
+    let m = Matrix4::identity().scale(2., 2., 2.).translate(0., 1., 0.);
+    let r2 = r.transform(&m);
+    assert_eq!(r2.origin, Point::new(2., 1., 2.));
+    assert_eq!(r2.direction, Vector::new(0., 0., 2.));
+    assert_eq!(r2.transform(&m.inverse()), r);
+}
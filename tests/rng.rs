@@ -0,0 +1,23 @@
+use ray_tracer::rng::Rng;
+
+#[test]
+fn rng_is_reproducible() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let xs: Vec<u32> = (0..8).map(|_| a.next_u32()).collect();
+    assert_eq!(xs, (0..8).map(|_| b.next_u32()).collect::<Vec<_>>());
+
+    let mut c = Rng::new(43);
+    assert_ne!(xs, (0..8).map(|_| c.next_u32()).collect::<Vec<_>>());
+    let mut d = Rng::with_stream(42, 1);
+    assert_ne!(xs, (0..8).map(|_| d.next_u32()).collect::<Vec<_>>());
+}
+
+#[test]
+fn rng_floats_are_uniform() {
+    let mut rng = Rng::new(1);
+    let samples: Vec<f32> = (0..10_000).map(|_| rng.next_f32()).collect();
+    assert!(samples.iter().all(|x| (0. ..1.).contains(x)));
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    assert!((mean - 0.5).abs() < 0.01, "{mean}");
+}
@@ -0,0 +1,108 @@
+use std::{
+    f32::consts::{FRAC_1_SQRT_2, PI},
+    sync::Mutex,
+};
+
+use ray_tracer::{
+    intersection::Intersections,
+    material::Material,
+    matrix::Matrix4,
+    ray::Ray,
+    shape::{Bounds, Shape, ShapeData},
+    tuple::{Point, Vector},
+};
+
+#[derive(Debug, Default)]
+struct TestShape {
+    data: ShapeData,
+    saved_ray: Mutex<Option<Ray>>,
+}
+
+impl Shape for TestShape {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect<'a>(&'a self, ray: &Ray, _xs: &mut Intersections<'a>) {
+        *self.saved_ray.lock().unwrap() = Some(*ray);
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        Vector::new(point.x, point.y, point.z)
+    }
+
+    fn local_bounds(&self) -> Bounds {
+        Bounds::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.))
+    }
+}
+
+#[test]
+fn default_transform() {
+    let s = TestShape::default();
+    assert_eq!(*s.transform(), Matrix4::IDENTITY);
+}
+
+#[test]
+fn assign_transform() {
+    let s = TestShape::default().with_transform(Matrix4::translation(2., 3., 4.));
+    assert_eq!(*s.transform(), Matrix4::translation(2., 3., 4.));
+}
+
+#[test]
+fn default_material() {
+    let s = TestShape::default();
+    assert_eq!(*s.material(), Material::default());
+}
+
+#[test]
+fn assign_material() {
+    let m = Material {
+        ambient: 1.,
+        ..Material::default()
+    };
+    let mut s = TestShape::default();
+    s.set_material(m.clone());
+    assert_eq!(*s.material(), m);
+}
+
+#[test]
+fn intersect_scaled_shape() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let s = TestShape::default().with_transform(Matrix4::scaling(2., 2., 2.));
+    s.intersect(&r, &mut Intersections::new());
+
+    let saved = s.saved_ray.lock().unwrap().unwrap();
+    assert_eq!(saved.origin, Point::new(0., 0., -2.5));
+    assert_eq!(saved.direction, Vector::new(0., 0., 0.5));
+}
+
+#[test]
+fn intersect_translated_shape() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let s = TestShape::default().with_transform(Matrix4::translation(5., 0., 0.));
+    s.intersect(&r, &mut Intersections::new());
+
+    let saved = s.saved_ray.lock().unwrap().unwrap();
+    assert_eq!(saved.origin, Point::new(-5., 0., -5.));
+    assert_eq!(saved.direction, Vector::new(0., 0., 1.));
+}
+
+#[test]
+fn normal_on_translated_shape() {
+    let s = TestShape::default().with_transform(Matrix4::translation(0., 1., 0.));
+    let n = s.normal_at(Point::new(0., 1. + FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+    assert_eq!(n, Vector::new(0., FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+}
+
+#[test]
+fn normal_on_transformed_shape() {
+    let m = Matrix4::identity().rotate_z(PI / 5.).scale(1., 0.5, 1.);
+    let s = TestShape::default().with_transform(m);
+    let v = 2f32.sqrt() / 2.;
+    let n = s.normal_at(Point::new(0., v, -v));
+    assert_eq!(n, Vector::new(0., 0.97014, -0.24254));
+}
@@ -0,0 +1,57 @@
+use ray_tracer::{
+    intersection::{Intersection, Intersections},
+    ray::Ray,
+    shape::{Shape, SmoothTriangle},
+    tuple::{Point, Vector},
+};
+
+fn smooth_triangle() -> SmoothTriangle {
+    SmoothTriangle::new(
+        Point::new(0., 1., 0.),
+        Point::new(-1., 0., 0.),
+        Point::new(1., 0., 0.),
+        Vector::new(0., 1., 0.),
+        Vector::new(-1., 0., 0.),
+        Vector::new(1., 0., 0.),
+    )
+}
+
+#[test]
+fn construct_smooth_triangle() {
+    let tri = smooth_triangle();
+    assert_eq!(tri.p1(), Point::new(0., 1., 0.));
+    assert_eq!(tri.p2(), Point::new(-1., 0., 0.));
+    assert_eq!(tri.p3(), Point::new(1., 0., 0.));
+    assert_eq!(tri.n1(), Vector::new(0., 1., 0.));
+    assert_eq!(tri.n2(), Vector::new(-1., 0., 0.));
+    assert_eq!(tri.n3(), Vector::new(1., 0., 0.));
+}
+
+#[test]
+fn intersection_stores_uv() {
+    let tri = smooth_triangle();
+    let r = Ray::new(Point::new(-0.2, 0.3, -2.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    tri.local_intersect(&r, &mut xs);
+    assert!((xs[0].u - 0.45).abs() < 1e-5);
+    assert!((xs[0].v - 0.25).abs() < 1e-5);
+}
+
+#[test]
+fn normal_interpolated_with_uv() {
+    let tri = smooth_triangle();
+    let i = Intersection::with_uv(1., &tri, 0.45, 0.25);
+    assert_eq!(
+        tri.normal_at_hit(Point::new(0., 0., 0.), &i),
+        Vector::new(-0.5547, 0.83205, 0.)
+    );
+}
+
+#[test]
+fn prepare_normal_on_smooth_triangle() {
+    let tri = smooth_triangle();
+    let i = Intersection::with_uv(1., &tri, 0.45, 0.25);
+    let r = Ray::new(Point::new(-0.2, 0.3, -2.), Vector::new(0., 0., 1.));
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(comps.normalv, Vector::new(-0.5547, 0.83205, 0.));
+}
@@ -0,0 +1,146 @@
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+
+use ray_tracer::{
+    intersection::Intersections,
+    material::Material,
+    matrix::Matrix4,
+    ray::Ray,
+    shape::{Shape, Sphere},
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn ray_intersects_sphere_at_two_points() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let s = Sphere::new();
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 4.);
+    assert_eq!(xs[1].t, 6.);
+}
+
+#[test]
+fn ray_intersects_sphere_at_tangent() {
+    let r = Ray::new(Point::new(0., 1., -5.), Vector::new(0., 0., 1.));
+    let s = Sphere::new();
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 5.);
+    assert_eq!(xs[1].t, 5.);
+}
+
+#[test]
+fn ray_misses_sphere() {
+    let r = Ray::new(Point::new(0., 2., -5.), Vector::new(0., 0., 1.));
+    let s = Sphere::new();
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn ray_originates_inside_sphere() {
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    let s = Sphere::new();
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, -1.);
+    assert_eq!(xs[1].t, 1.);
+}
+
+#[test]
+fn sphere_behind_ray() {
+    let r = Ray::new(Point::new(0., 0., 5.), Vector::new(0., 0., 1.));
+    let s = Sphere::new();
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, -6.);
+    assert_eq!(xs[1].t, -4.);
+}
+
+#[test]
+fn sphere_default_transform() {
+    let s = Sphere::new();
+    assert_eq!(*s.transform(), Matrix4::IDENTITY);
+
+    let t = Matrix4::translation(2., 3., 4.);
+    let s = s.with_transform(t);
+    assert_eq!(*s.transform(), t);
+}
+
+#[test]
+fn intersect_scaled_sphere() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let s = Sphere::new().with_transform(Matrix4::scaling(2., 2., 2.));
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 3.);
+    assert_eq!(xs[1].t, 7.);
+}
+
+#[test]
+fn intersect_translated_sphere() {
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let s = Sphere::new().with_transform(Matrix4::translation(5., 0., 0.));
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn normal_on_sphere() {
+    let s = Sphere::new();
+    assert_eq!(s.normal_at(Point::new(1., 0., 0.)), Vector::new(1., 0., 0.));
+    assert_eq!(s.normal_at(Point::new(0., 1., 0.)), Vector::new(0., 1., 0.));
+    assert_eq!(s.normal_at(Point::new(0., 0., 1.)), Vector::new(0., 0., 1.));
+
+    let v = 3f32.sqrt() / 3.;
+    let n = s.normal_at(Point::new(v, v, v));
+    assert_eq!(n, Vector::new(v, v, v));
+    assert_eq!(n, n.normalize());
+}
+
+#[test]
+fn normal_on_translated_sphere() {
+    let s = Sphere::new().with_transform(Matrix4::translation(0., 1., 0.));
+    let n = s.normal_at(Point::new(0., 1. + FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+    assert_eq!(n, Vector::new(0., FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+}
+
+#[test]
+fn normal_on_transformed_sphere() {
+    let m = Matrix4::identity().rotate_z(PI / 5.).scale(1., 0.5, 1.);
+    let s = Sphere::new().with_transform(m);
+    let v = 2f32.sqrt() / 2.;
+    let n = s.normal_at(Point::new(0., v, -v));
+    assert_eq!(n, Vector::new(0., 0.97014, -0.24254));
+}
+
+#[test]
+fn sphere_material() {
+    let s = Sphere::new();
+    assert_eq!(*s.material(), Material::default());
+
+    let m = Material {
+        ambient: 1.,
+        ..Material::default()
+    };
+    let s = Sphere::new().with_material(m.clone());
+    assert_eq!(*s.material(), m);
+}
+
+#[test]
+fn intersect_sphere_far_from_origin() {
+    let s = Sphere::new().with_transform(Matrix4::translation(50., 50., 50.));
+    let r = Ray::new(Point::new(50., 50., 0.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    s.intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 49.);
+    assert_eq!(xs[1].t, 51.);
+}
@@ -0,0 +1,192 @@
+use std::f32::consts::PI;
+
+use ray_tracer::{
+    matrix::{Matrix, Matrix4},
+    tuple::{Point, Vector},
+};
+
+#[test]
+fn translation() {
+    let transform = Matrix::translation(5., -3., 2.);
+    let p = Point::new(-3., 4., 5.);
+    assert_eq!(transform.clone() * p, Point::new(2., 1., 7.));
+    assert_eq!(transform.inverse() * p, Point::new(-8., 7., 3.));
+}
+
+#[test]
+fn translation_does_not_affect_vectors() {
+    let transform = Matrix::translation(5., -3., 2.);
+    let v = Vector::new(-3., 4., 5.);
+    assert_eq!(transform * v, v);
+}
+
+#[test]
+fn scaling() {
+    let transform = Matrix::scaling(2., 3., 4.);
+    assert_eq!(
+        transform.clone() * Point::new(-4., 6., 8.),
+        Point::new(-8., 18., 32.)
+    );
+    assert_eq!(
+        transform.clone() * Vector::new(-4., 6., 8.),
+        Vector::new(-8., 18., 32.)
+    );
+    assert_eq!(
+        transform.inverse() * Vector::new(-4., 6., 8.),
+        Vector::new(-2., 2., 2.)
+    );
+}
+
+#[test]
+fn reflection_is_negative_scaling() {
+    let transform = Matrix::scaling(-1., 1., 1.);
+    assert_eq!(transform * Point::new(2., 3., 4.), Point::new(-2., 3., 4.));
+}
+
+#[test]
+fn rotation_x() {
+    let p = Point::new(0., 1., 0.);
+    let half_quarter = Matrix::rotation_x(PI / 4.);
+    let full_quarter = Matrix::rotation_x(PI / 2.);
+    let r = 2f32.sqrt() / 2.;
+
+    assert_eq!(half_quarter.clone() * p, Point::new(0., r, r));
+    assert_eq!(full_quarter * p, Point::new(0., 0., 1.));
+    assert_eq!(half_quarter.inverse() * p, Point::new(0., r, -r));
+}
+
+#[test]
+fn rotation_y() {
+    let p = Point::new(0., 0., 1.);
+    let r = 2f32.sqrt() / 2.;
+    assert_eq!(Matrix::rotation_y(PI / 4.) * p, Point::new(r, 0., r));
+    assert_eq!(Matrix::rotation_y(PI / 2.) * p, Point::new(1., 0., 0.));
+}
+
+#[test]
+fn rotation_z() {
+    let p = Point::new(0., 1., 0.);
+    let r = 2f32.sqrt() / 2.;
+    assert_eq!(Matrix::rotation_z(PI / 4.) * p, Point::new(-r, r, 0.));
+    assert_eq!(Matrix::rotation_z(PI / 2.) * p, Point::new(-1., 0., 0.));
+}
+
+#[test]
+fn rotation_arbitrary_axis() {
+    assert_eq!(
+        Matrix::rotation(Vector::new(1., 0., 0.), 0.7),
+        Matrix::rotation_x(0.7)
+    );
+    assert_eq!(
+        Matrix::rotation(Vector::new(0., 2., 0.), 0.7),
+        Matrix::rotation_y(0.7)
+    );
+    assert_eq!(
+        Matrix::rotation(Vector::new(0., 0., 1.), 0.7),
+        Matrix::rotation_z(0.7)
+    );
+
+    let axis = Vector::new(1., 1., 1.);
+    let p = Point::new(1., 0., 0.);
+    assert_eq!(
+        Matrix::rotation(axis, 2. * PI / 3.) * p,
+        Point::new(0., 1., 0.)
+    );
+}
+
+#[test]
+fn shearing() {
+    let p = Point::new(2., 3., 4.);
+    let cases = [
+        ([1., 0., 0., 0., 0., 0.], Point::new(5., 3., 4.)),
+        ([0., 1., 0., 0., 0., 0.], Point::new(6., 3., 4.)),
+        ([0., 0., 1., 0., 0., 0.], Point::new(2., 5., 4.)),
+        ([0., 0., 0., 1., 0., 0.], Point::new(2., 7., 4.)),
+        ([0., 0., 0., 0., 1., 0.], Point::new(2., 3., 6.)),
+        ([0., 0., 0., 0., 0., 1.], Point::new(2., 3., 7.)),
+    ];
+
+    for ([xy, xz, yx, yz, zx, zy], expected) in cases {
+        assert_eq!(Matrix::shearing(xy, xz, yx, yz, zx, zy) * p, expected);
+    }
+}
+
+#[test]
+fn transformations_in_sequence() {
+    let p = Point::new(1., 0., 1.);
+    let a = Matrix::rotation_x(PI / 2.);
+    let b = Matrix::scaling(5., 5., 5.);
+    let c = Matrix::translation(10., 5., 7.);
+
+    let p2 = a.clone() * p;
+    assert_eq!(p2, Point::new(1., -1., 0.));
+    let p3 = b.clone() * p2;
+    assert_eq!(p3, Point::new(5., -5., 0.));
+    let p4 = c.clone() * p3;
+    assert_eq!(p4, Point::new(15., 0., 7.));
+
+    assert_eq!(c * b * a * p, Point::new(15., 0., 7.));
+}
+
+#[test]
+fn fluent_transformations() {
+    let p = Point::new(1., 0., 1.);
+    let transform = Matrix::identity(4, 4)
+        .rotate_x(PI / 2.)
+        .scale(5., 5., 5.)
+        .translate(10., 5., 7.);
+    assert_eq!(transform * p, Point::new(15., 0., 7.));
+
+    let transform = Matrix::identity(4, 4)
+        .shear(1., 0., 0., 0., 0., 0.)
+        .rotate(Vector::new(0., 0., 1.), PI / 2.);
+    assert_eq!(transform * Point::new(2., 3., 4.), Point::new(-3., 5., 4.));
+}
+
+#[test]
+fn view_transform_default_orientation() {
+    let t = Matrix4::view_transform(
+        Point::new(0., 0., 0.),
+        Point::new(0., 0., -1.),
+        Vector::new(0., 1., 0.),
+    );
+    assert_eq!(t, Matrix4::IDENTITY);
+}
+
+#[test]
+fn view_transform_positive_z() {
+    let t = Matrix4::view_transform(
+        Point::new(0., 0., 0.),
+        Point::new(0., 0., 1.),
+        Vector::new(0., 1., 0.),
+    );
+    assert_eq!(t, Matrix4::scaling(-1., 1., -1.));
+}
+
+#[test]
+fn view_transform_moves_world() {
+    let t = Matrix4::view_transform(
+        Point::new(0., 0., 8.),
+        Point::new(0., 0., 0.),
+        Vector::new(0., 1., 0.),
+    );
+    assert_eq!(t, Matrix4::translation(0., 0., -8.));
+}
+
+#[test]
+fn view_transform_arbitrary() {
+    let t = Matrix4::view_transform(
+        Point::new(1., 3., 2.),
+        Point::new(4., -2., 8.),
+        Vector::new(1., 1., 0.),
+    );
+    assert_eq!(
+        t,
+        Matrix4::new([
+            [-0.50709, 0.50709, 0.67612, -2.36643],
+            [0.76772, 0.60609, 0.12122, -2.82843],
+            [-0.35857, 0.59761, -0.71714, 0.],
+            [0., 0., 0., 1.],
+        ])
+    );
+}
@@ -0,0 +1,86 @@
+use ray_tracer::{
+    intersection::Intersections,
+    ray::Ray,
+    shape::{Shape, Triangle},
+    tuple::{Point, Vector},
+};
+
+fn triangle() -> Triangle {
+    Triangle::new(
+        Point::new(0., 1., 0.),
+        Point::new(-1., 0., 0.),
+        Point::new(1., 0., 0.),
+    )
+}
+
+#[test]
+fn construct_triangle() {
+    let t = triangle();
+    assert_eq!(t.p1(), Point::new(0., 1., 0.));
+    assert_eq!(t.p2(), Point::new(-1., 0., 0.));
+    assert_eq!(t.p3(), Point::new(1., 0., 0.));
+    assert_eq!(t.e1(), Vector::new(-1., -1., 0.));
+    assert_eq!(t.e2(), Vector::new(1., -1., 0.));
+    assert_eq!(t.normal(), Vector::new(0., 0., -1.));
+}
+
+#[test]
+fn normal_on_triangle() {
+    let t = triangle();
+    assert_eq!(t.local_normal_at(Point::new(0., 0.5, 0.)), t.normal());
+    assert_eq!(t.local_normal_at(Point::new(-0.5, 0.75, 0.)), t.normal());
+    assert_eq!(t.local_normal_at(Point::new(0.5, 0.25, 0.)), t.normal());
+}
+
+#[test]
+fn intersect_parallel_ray() {
+    let t = triangle();
+    let r = Ray::new(Point::new(0., -1., -2.), Vector::new(0., 1., 0.));
+    let mut xs = Intersections::new();
+    t.local_intersect(&r, &mut xs);
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn ray_misses_edges() {
+    let t = triangle();
+    let mut xs = Intersections::new();
+    for origin in [
+        Point::new(1., 1., -2.),
+        Point::new(-1., 1., -2.),
+        Point::new(0., -1., -2.),
+    ] {
+        let r = Ray::new(origin, Vector::new(0., 0., 1.));
+        t.local_intersect(&r, &mut xs);
+    }
+    assert!(xs.is_empty());
+}
+
+#[test]
+fn ray_strikes_triangle() {
+    let t = triangle();
+    let r = Ray::new(Point::new(0., 0.5, -2.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    t.local_intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t, 2.);
+}
+
+#[test]
+fn ray_strikes_tiny_triangle() {
+    // Edges of 1e-4, as in a finely tessellated mesh.
+    let t = Triangle::new(
+        Point::new(0., 1e-4, 0.),
+        Point::new(-1e-4, 0., 0.),
+        Point::new(1e-4, 0., 0.),
+    );
+    let r = Ray::new(Point::new(0., 5e-5, -2.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    t.local_intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t, 2.);
+
+    let r = Ray::new(Point::new(0., -1., -2.), Vector::new(0., 1., 0.));
+    t.local_intersect(&r, &mut xs);
+    assert_eq!(xs.len(), 1);
+}
@@ -132,3 +132,15 @@ fn vec_cross() {
     assert_eq!(a.cross(&b), Vector::new(-1., 2., -1.));
     assert_eq!(b.cross(&a), Vector::new(1., -2., 1.));
 }
+
+#[test]
+fn vec_reflect() {
+    let v = Vector::new(1., -1., 0.);
+    let n = Vector::new(0., 1., 0.);
+    assert_eq!(v.reflect(&n), Vector::new(1., 1., 0.));
+
+    let v = Vector::new(0., -1., 0.);
+    let r = 2_f32.sqrt() / 2.;
+    let n = Vector::new(r, r, 0.);
+    assert_eq!(v.reflect(&n), Vector::new(1., 0., 0.));
+}
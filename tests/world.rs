@@ -0,0 +1,389 @@
+mod common;
+
+use common::{default_world, glass_sphere};
+use ray_tracer::{
+    color::Color,
+    intersection::{Intersection, Intersections},
+    light::PointLight,
+    material::Material,
+    matrix::Matrix4,
+    pattern::{Pattern, PatternData},
+    ray::Ray,
+    shape::{Plane, Shape, Sphere},
+    tuple::{Point, Vector},
+    world::World,
+};
+
+#[test]
+fn empty_world() {
+    let w = World::new();
+    assert!(w.objects.is_empty());
+    assert!(w.lights.is_empty());
+    assert_eq!(w.max_depth, World::DEFAULT_MAX_DEPTH);
+}
+
+#[test]
+fn intersect_world_with_ray() {
+    let w = default_world();
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let mut xs = Intersections::new();
+    w.intersect(&r, &mut xs);
+    assert_eq!(
+        xs.iter().map(|i| i.t).collect::<Vec<_>>(),
+        vec![4., 4.5, 5.5, 6.]
+    );
+}
+
+#[test]
+fn shade_intersection() {
+    let w = default_world();
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let i = Intersection::new(4., w.objects[0].as_ref());
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(
+        w.shade_hit(&comps, World::DEFAULT_MAX_DEPTH, &mut Intersections::new()),
+        Color::new(0.38066, 0.47583, 0.2855)
+    );
+}
+
+#[test]
+fn shade_intersection_from_inside() {
+    let mut w = default_world();
+    w.lights = vec![PointLight::new(
+        Point::new(0., 0.25, 0.),
+        Color::new(1., 1., 1.),
+    )];
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    let i = Intersection::new(0.5, w.objects[1].as_ref());
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(
+        w.shade_hit(&comps, World::DEFAULT_MAX_DEPTH, &mut Intersections::new()),
+        Color::new(0.90498, 0.90498, 0.90498)
+    );
+}
+
+#[test]
+fn color_when_ray_misses() {
+    let w = default_world();
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 1., 0.));
+    assert_eq!(w.color_at(&r, &mut Intersections::new()), Color::BLACK);
+}
+
+#[test]
+fn color_when_ray_hits() {
+    let w = default_world();
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    assert_eq!(
+        w.color_at(&r, &mut Intersections::new()),
+        Color::new(0.38066, 0.47583, 0.2855)
+    );
+}
+
+#[test]
+fn color_with_intersection_behind_ray() {
+    let mut w = default_world();
+    w.objects[0].material_mut().ambient = 1.;
+    w.objects[1].material_mut().ambient = 1.;
+    let inner_color = w.objects[1].material().color;
+    let r = Ray::new(Point::new(0., 0., 0.75), Vector::new(0., 0., -1.));
+    assert_eq!(w.color_at(&r, &mut Intersections::new()), inner_color);
+}
+
+#[test]
+fn no_shadow_when_nothing_collinear() {
+    let w = default_world();
+    let light = w.lights[0].position;
+    assert!(!w.is_shadowed(light, Point::new(0., 10., 0.), &mut Intersections::new()));
+}
+
+#[test]
+fn shadow_when_object_between_point_and_light() {
+    let w = default_world();
+    let light = w.lights[0].position;
+    assert!(w.is_shadowed(light, Point::new(10., -10., 10.), &mut Intersections::new()));
+}
+
+#[test]
+fn no_shadow_when_object_behind_light() {
+    let w = default_world();
+    let light = w.lights[0].position;
+    assert!(!w.is_shadowed(
+        light,
+        Point::new(-20., 20., -20.),
+        &mut Intersections::new()
+    ));
+}
+
+#[test]
+fn no_shadow_when_object_behind_point() {
+    let w = default_world();
+    let light = w.lights[0].position;
+    assert!(!w.is_shadowed(light, Point::new(-2., 2., -2.), &mut Intersections::new()));
+}
+
+#[test]
+fn shade_hit_in_shadow() {
+    let w = World::new()
+        .with_light(PointLight::new(
+            Point::new(0., 0., -10.),
+            Color::new(1., 1., 1.),
+        ))
+        .with_object(Sphere::new())
+        .with_object(Sphere::new().with_transform(Matrix4::translation(0., 0., 10.)));
+    let r = Ray::new(Point::new(0., 0., 5.), Vector::new(0., 0., 1.));
+    let i = Intersection::new(4., w.objects[1].as_ref());
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(
+        w.shade_hit(&comps, World::DEFAULT_MAX_DEPTH, &mut Intersections::new()),
+        Color::new(0.1, 0.1, 0.1)
+    );
+}
+
+fn reflective_plane() -> Plane {
+    Plane::new()
+        .with_material(Material {
+            reflective: 0.5,
+            ..Material::default()
+        })
+        .with_transform(Matrix4::translation(0., -1., 0.))
+}
+
+#[test]
+fn reflected_color_for_nonreflective_material() {
+    let mut w = default_world();
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    w.objects[1].material_mut().ambient = 1.;
+    let i = Intersection::new(1., w.objects[1].as_ref());
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(
+        w.reflected_color(&comps, World::DEFAULT_MAX_DEPTH, &mut Intersections::new()),
+        Color::BLACK
+    );
+}
+
+#[test]
+fn reflected_color_for_reflective_material() {
+    let w = default_world().with_object(reflective_plane());
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 0., -3.), Vector::new(0., -v, v));
+    let i = Intersection::new(2_f32.sqrt(), w.objects[2].as_ref());
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(
+        w.reflected_color(&comps, World::DEFAULT_MAX_DEPTH, &mut Intersections::new()),
+        Color::new(0.19033, 0.23791, 0.14274)
+    );
+}
+
+#[test]
+fn shade_hit_with_reflective_material() {
+    let w = default_world().with_object(reflective_plane());
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 0., -3.), Vector::new(0., -v, v));
+    let i = Intersection::new(2_f32.sqrt(), w.objects[2].as_ref());
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(
+        w.shade_hit(&comps, World::DEFAULT_MAX_DEPTH, &mut Intersections::new()),
+        Color::new(0.87675, 0.92434, 0.82917)
+    );
+}
+
+#[test]
+fn mutually_reflective_surfaces_terminate() {
+    let mirror = Material {
+        reflective: 1.,
+        ..Material::default()
+    };
+    let w = World::new()
+        .with_light(PointLight::new(
+            Point::new(0., 0., 0.),
+            Color::new(1., 1., 1.),
+        ))
+        .with_object(
+            Plane::new()
+                .with_material(mirror.clone())
+                .with_transform(Matrix4::translation(0., -1., 0.)),
+        )
+        .with_object(
+            Plane::new()
+                .with_material(mirror)
+                .with_transform(Matrix4::rotation_x(std::f32::consts::PI).translate(0., 1., 0.)),
+        );
+    let r = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 1., 0.));
+
+    let color = w.color_at(&r, &mut Intersections::new());
+    let shallow = w.with_max_depth(1).color_at(&r, &mut Intersections::new());
+    assert!(color.r > shallow.r);
+}
+
+#[test]
+fn reflected_color_at_max_depth() {
+    let w = default_world().with_object(reflective_plane());
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 0., -3.), Vector::new(0., -v, v));
+    let i = Intersection::new(2_f32.sqrt(), w.objects[2].as_ref());
+    let comps = i.prepare_computations(&r, &[i]);
+    assert_eq!(
+        w.reflected_color(&comps, 0, &mut Intersections::new()),
+        Color::BLACK
+    );
+}
+
+#[derive(Debug, Default)]
+struct PositionPattern {
+    data: PatternData,
+}
+
+impl Pattern for PositionPattern {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        Color::new(point.x, point.y, point.z)
+    }
+}
+
+#[test]
+fn refracted_color_with_opaque_surface() {
+    let w = default_world();
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let shape = w.objects[0].as_ref();
+    let xs = Intersections::from_iter([Intersection::new(4., shape), Intersection::new(6., shape)]);
+    let comps = xs[0].prepare_computations(&r, &xs);
+    assert_eq!(
+        w.refracted_color(&comps, 5, &mut Intersections::new()),
+        Color::BLACK
+    );
+}
+
+#[test]
+fn refracted_color_at_max_depth() {
+    let mut w = default_world();
+    w.objects[0].material_mut().transparency = 1.;
+    w.objects[0].material_mut().refractive_index = 1.5;
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let shape = w.objects[0].as_ref();
+    let xs = Intersections::from_iter([Intersection::new(4., shape), Intersection::new(6., shape)]);
+    let comps = xs[0].prepare_computations(&r, &xs);
+    assert_eq!(
+        w.refracted_color(&comps, 0, &mut Intersections::new()),
+        Color::BLACK
+    );
+}
+
+#[test]
+fn refracted_color_under_total_internal_reflection() {
+    let mut w = default_world();
+    w.objects[0].material_mut().transparency = 1.;
+    w.objects[0].material_mut().refractive_index = 1.5;
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 0., v), Vector::new(0., 1., 0.));
+    let shape = w.objects[0].as_ref();
+    let xs = Intersections::from_iter([Intersection::new(-v, shape), Intersection::new(v, shape)]);
+    let comps = xs[1].prepare_computations(&r, &xs);
+    assert_eq!(
+        w.refracted_color(&comps, 5, &mut Intersections::new()),
+        Color::BLACK
+    );
+}
+
+#[test]
+fn refracted_color_with_refracted_ray() {
+    let mut w = default_world();
+    let material = Material {
+        ambient: 1.,
+        ..w.objects[0].material().clone()
+    }
+    .with_pattern(PositionPattern::default());
+    w.objects[0].set_material(material);
+    w.objects[1].material_mut().transparency = 1.;
+    w.objects[1].material_mut().refractive_index = 1.5;
+
+    let r = Ray::new(Point::new(0., 0., 0.1), Vector::new(0., 1., 0.));
+    let (a, b) = (w.objects[0].as_ref(), w.objects[1].as_ref());
+    let xs = Intersections::from_iter([
+        Intersection::new(-0.9899, a),
+        Intersection::new(-0.4899, b),
+        Intersection::new(0.4899, b),
+        Intersection::new(0.9899, a),
+    ]);
+    let comps = xs[2].prepare_computations(&r, &xs);
+    let color = w.refracted_color(&comps, 5, &mut Intersections::new());
+    assert!((color.r - 0.).abs() < 1e-3);
+    assert!((color.g - 0.99888).abs() < 1e-3);
+    assert!((color.b - 0.04725).abs() < 1e-3);
+}
+
+#[test]
+fn shade_hit_with_transparent_material() {
+    let floor = Plane::new()
+        .with_transform(Matrix4::translation(0., -1., 0.))
+        .with_material(Material {
+            transparency: 0.5,
+            refractive_index: 1.5,
+            ..Material::default()
+        });
+    let ball = Sphere::new()
+        .with_transform(Matrix4::translation(0., -3.5, -0.5))
+        .with_material(Material {
+            color: Color::new(1., 0., 0.),
+            ambient: 0.5,
+            ..Material::default()
+        });
+    let w = default_world().with_object(floor).with_object(ball);
+
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 0., -3.), Vector::new(0., -v, v));
+    let xs = Intersections::from_iter([Intersection::new(2_f32.sqrt(), w.objects[2].as_ref())]);
+    let comps = xs[0].prepare_computations(&r, &xs);
+    let color = w.shade_hit(&comps, 5, &mut Intersections::new());
+    assert!((color.r - 0.93642).abs() < 1e-4);
+    assert!((color.g - 0.68642).abs() < 1e-4);
+    assert!((color.b - 0.68642).abs() < 1e-4);
+}
+
+#[test]
+fn shade_hit_with_reflective_transparent_material() {
+    let floor = Plane::new()
+        .with_transform(Matrix4::translation(0., -1., 0.))
+        .with_material(Material {
+            reflective: 0.5,
+            transparency: 0.5,
+            refractive_index: 1.5,
+            ..Material::default()
+        });
+    let ball = Sphere::new()
+        .with_transform(Matrix4::translation(0., -3.5, -0.5))
+        .with_material(Material {
+            color: Color::new(1., 0., 0.),
+            ambient: 0.5,
+            ..Material::default()
+        });
+    let w = default_world().with_object(floor).with_object(ball);
+
+    let v = 2_f32.sqrt() / 2.;
+    let r = Ray::new(Point::new(0., 0., -3.), Vector::new(0., -v, v));
+    let xs = Intersections::from_iter([Intersection::new(2_f32.sqrt(), w.objects[2].as_ref())]);
+    let comps = xs[0].prepare_computations(&r, &xs);
+    let color = w.shade_hit(&comps, 5, &mut Intersections::new());
+    assert!((color.r - 0.93391).abs() < 1e-4);
+    assert!((color.g - 0.69643).abs() < 1e-4);
+    assert!((color.b - 0.69243).abs() < 1e-4);
+}
+
+#[test]
+fn glass_sphere_in_world() {
+    let w = World::new()
+        .with_light(PointLight::new(
+            Point::new(-10., 10., -10.),
+            Color::new(1., 1., 1.),
+        ))
+        .with_object(glass_sphere());
+    let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let color = w.color_at(&r, &mut Intersections::new());
+    assert!(color.r > 0.1 && color.r < 1.);
+}